@@ -0,0 +1,48 @@
+use std::sync::Arc;
+use serde::Deserialize;
+use crate::effect::Ability;
+use crate::keyword::Keyword;
+
+// Card definition, loaded from `assets/cards/*.card.ron`
+#[derive(Clone, Debug, Deserialize)]
+pub struct CardData {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub cost: u32,
+    // Attack/health for cards that stay on the board; spells have none
+    #[serde(default)]
+    pub stats: Option<CardStats>,
+    #[serde(default)]
+    pub rules_text: String,
+    // Art image path, relative to `assets/`
+    #[serde(default)]
+    pub art: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    // Keyword abilities; the card face shows them, so `rules_text` doesn't repeat them
+    #[serde(default)]
+    pub keywords: Vec<Keyword>,
+    // Triggered and activated abilities; `rules_text` describes them to the player
+    #[serde(default)]
+    pub abilities: Vec<Ability>,
+}
+
+// Combat stats for a card
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq)]
+pub struct CardStats {
+    pub attack: u32,
+    pub health: u32,
+}
+
+// Identifies one physical card for the whole match, whichever zone it is in. Copies of the
+// same definition get different ids.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct CardId(pub u32);
+
+// One card in a match: its id and the definition it was built from
+#[derive(Debug, Clone)]
+pub struct CardInstance {
+    pub id: CardId,
+    pub data: Arc<CardData>,
+}
@@ -0,0 +1,637 @@
+use std::sync::Arc;
+use serde::Deserialize;
+use crate::card::CardData;
+use crate::state::{MatchState, PlayError, PlayerSide, Unit};
+
+// When a triggered ability fires
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum Trigger {
+    // A creature entering the board, or a spell being cast
+    OnPlay,
+    // The creature being destroyed
+    OnDeath,
+    // The start or end of its controller's turn, while the creature is on the board
+    StartOfTurn,
+    EndOfTurn,
+}
+
+// Who or what an effect applies to, seen from the side that controls the card
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum Target {
+    // Players
+    You,
+    Opponent,
+    // The creature the ability belongs to
+    This,
+    // The frontmost enemy creature in this one's lane
+    Opposite,
+    // Every creature on one or both sides of the board
+    FriendlyCreatures,
+    EnemyCreatures,
+    AllCreatures,
+    // Picked by the controller when the card is played or the ability used. Only on-play and
+    // activated abilities can have these, and all of a play's chosen targets are one choice.
+    ChosenCreature,
+    ChosenEnemyCreature,
+    ChosenFriendlyCreature,
+    ChosenPlayer,
+    // A player or a creature
+    ChosenAny,
+}
+
+impl Target {
+    pub fn is_chosen(&self) -> bool {
+        matches!(
+            self,
+            Target::ChosenCreature
+                | Target::ChosenEnemyCreature
+                | Target::ChosenFriendlyCreature
+                | Target::ChosenPlayer
+                | Target::ChosenAny
+        )
+    }
+}
+
+// The player or creature picked for a chosen target
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TargetChoice {
+    Player(PlayerSide),
+    Creature { side: PlayerSide, slot: usize },
+}
+
+// One thing a card does. Targets that make no sense for an effect (healing a spell, making
+// a creature discard) are ignored.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub enum Effect {
+    // The controller draws cards; drawing from an empty deck does nothing
+    Draw { count: u32 },
+    // A player discards their most recently drawn cards
+    Discard { count: u32, target: Target },
+    Damage { amount: u32, target: Target },
+    // Creatures can't be healed past their maximum health; players can
+    Heal { amount: u32, target: Target },
+    // Change attack and (maximum) health; a negative buff can destroy a creature
+    Buff { attack: i32, health: i32, target: Target },
+    Destroy { target: Target },
+    // Creatures go back to their owner's hand, losing any damage and buffs
+    ReturnToHand { target: Target },
+    // Run a script file (relative to `assets/`); needs the `scripting` feature. Whoever loads
+    // the card fills in `source`.
+    Script {
+        path: String,
+        #[serde(skip)]
+        source: Arc<str>,
+    },
+}
+
+// Something a card can do, declared in its definition
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub enum Ability {
+    // Happens by itself when `trigger` fires
+    Triggered { trigger: Trigger, effects: Vec<Effect> },
+    // Used by the controller during their main phase, once per turn. Costs `mana`, and with
+    // `exhaust` the creature must be ready and can't attack afterwards.
+    Activated {
+        #[serde(default)]
+        mana: u32,
+        #[serde(default)]
+        exhaust: bool,
+        effects: Vec<Effect>,
+    },
+}
+
+impl Effect {
+    pub fn target(&self) -> Option<Target> {
+        match *self {
+            Effect::Discard { target, .. }
+            | Effect::Damage { target, .. }
+            | Effect::Heal { target, .. }
+            | Effect::Buff { target, .. }
+            | Effect::Destroy { target }
+            | Effect::ReturnToHand { target } => Some(target),
+            Effect::Draw { .. } | Effect::Script { .. } => None,
+        }
+    }
+}
+
+impl Ability {
+    pub fn effects(&self) -> &[Effect] {
+        match self {
+            Ability::Triggered { effects, .. } | Ability::Activated { effects, .. } => effects,
+        }
+    }
+
+    pub fn effects_mut(&mut self) -> &mut Vec<Effect> {
+        match self {
+            Ability::Triggered { effects, .. } | Ability::Activated { effects, .. } => effects,
+        }
+    }
+
+    // What this ability's controller has to pick, if anything
+    pub fn chosen_target(&self) -> Option<Target> {
+        self.effects().iter().filter_map(Effect::target).find(Target::is_chosen)
+    }
+}
+
+impl CardData {
+    // What the player has to pick when playing this card, if anything
+    pub fn play_target(&self) -> Option<Target> {
+        self.abilities
+            .iter()
+            .filter(|ability| matches!(ability, Ability::Triggered { trigger: Trigger::OnPlay, .. }))
+            .find_map(Ability::chosen_target)
+    }
+
+    // The effect lists of this card's abilities with the given trigger
+    pub fn triggered(&self, trigger: Trigger) -> impl Iterator<Item = &[Effect]> {
+        self.abilities.iter().filter_map(move |ability| match ability {
+            Ability::Triggered { trigger: fired, effects } if *fired == trigger => Some(effects.as_slice()),
+            _ => None,
+        })
+    }
+
+    // Every script effect's path and source, for a loader to fill in
+    pub fn scripts_mut(&mut self) -> impl Iterator<Item = (&str, &mut Arc<str>)> {
+        self.abilities
+            .iter_mut()
+            .flat_map(|ability| ability.effects_mut().iter_mut())
+            .filter_map(|effect| match effect {
+                Effect::Script { path, source } => Some((path.as_str(), source)),
+                _ => None,
+            })
+    }
+}
+
+// Where effects come from: the side controlling the card, for creatures the slot it is in (or
+// was in, for death triggers), and the target picked for them
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Source {
+    pub controller: PlayerSide,
+    pub slot: Option<usize>,
+    pub chosen: Option<TargetChoice>,
+}
+
+impl MatchState {
+    // Resolve a list of effects in order. Creatures destroyed along the way are removed (and
+    // their death triggers resolved) before the next effect.
+    pub(crate) fn resolve_effects(&mut self, source: Source, effects: &[Effect]) {
+        for effect in effects {
+            if self.is_over() {
+                return;
+            }
+            self.resolve_effect(source, effect);
+            self.remove_destroyed();
+            self.check_life_totals();
+        }
+    }
+
+    fn resolve_effect(&mut self, source: Source, effect: &Effect) {
+        match *effect {
+            Effect::Draw { count } => {
+                for _ in 0..count {
+                    self.draw(source.controller);
+                }
+            }
+            Effect::Discard { count, target } => {
+                for side in self.target_players(source, target) {
+                    let player = self.side_mut(side);
+                    for _ in 0..count {
+                        if let Some(card) = player.hand.pop() {
+                            player.graveyard.push(card);
+                        }
+                    }
+                }
+            }
+            // Life and health saturate rather than wrap; card loading rejects amounts that
+            // don't fit an i32 in the first place
+            Effect::Damage { amount, target } => {
+                let amount = i32::try_from(amount).unwrap_or(i32::MAX);
+                for side in self.target_players(source, target) {
+                    let player = self.side_mut(side);
+                    player.life = player.life.saturating_sub(amount);
+                }
+                for unit in self.target_units(source, target) {
+                    unit.health = unit.health.saturating_sub(amount);
+                }
+            }
+            Effect::Heal { amount, target } => {
+                let amount = i32::try_from(amount).unwrap_or(i32::MAX);
+                for side in self.target_players(source, target) {
+                    let player = self.side_mut(side);
+                    player.life = player.life.saturating_add(amount);
+                }
+                for unit in self.target_units(source, target) {
+                    unit.health = unit.health.saturating_add(amount).min(unit.max_health);
+                }
+            }
+            Effect::Buff { attack, health, target } => {
+                for unit in self.target_units(source, target) {
+                    unit.attack = unit.attack.saturating_add_signed(attack);
+                    unit.max_health = unit.max_health.saturating_add(health);
+                    unit.health = unit.health.saturating_add(health);
+                }
+            }
+            Effect::Destroy { target } => {
+                // Removed with the rest of the dead once the effect is done
+                for unit in self.target_units(source, target) {
+                    unit.health = unit.health.min(0);
+                }
+            }
+            Effect::ReturnToHand { target } => {
+                for (side, slot) in self.target_slots(source, target) {
+                    let player = self.side_mut(side);
+                    if let Some(unit) = player.board[slot].take() {
+                        player.hand.push(unit.card);
+                    }
+                }
+            }
+            Effect::Script { ref path, source: ref script } => self.run_script(source, path, script),
+        }
+    }
+
+    // The players a target refers to
+    fn target_players(&self, source: Source, target: Target) -> Vec<PlayerSide> {
+        match (target, source.chosen) {
+            (Target::You, _) => vec![source.controller],
+            (Target::Opponent, _) => vec![source.controller.other()],
+            (Target::ChosenPlayer | Target::ChosenAny, Some(TargetChoice::Player(side))) => vec![side],
+            _ => Vec::new(),
+        }
+    }
+
+    // The occupied board slots a target refers to, controller's side first
+    fn target_slots(&self, source: Source, target: Target) -> Vec<(PlayerSide, usize)> {
+        let friendly = source.controller;
+        let enemy = friendly.other();
+        let occupied = |side: PlayerSide| {
+            self.side(side)
+                .board
+                .iter()
+                .enumerate()
+                .filter(|(_, unit)| unit.is_some())
+                .map(move |(slot, _)| (side, slot))
+        };
+
+        let candidates: Vec<(PlayerSide, usize)> = match target {
+            Target::You | Target::Opponent | Target::ChosenPlayer => Vec::new(),
+            Target::ChosenCreature
+            | Target::ChosenEnemyCreature
+            | Target::ChosenFriendlyCreature
+            | Target::ChosenAny => match source.chosen {
+                Some(TargetChoice::Creature { side, slot }) => vec![(side, slot)],
+                _ => Vec::new(),
+            },
+            Target::This => source.slot.map(|slot| (friendly, slot)).into_iter().collect(),
+            Target::Opposite => source
+                .slot
+                .and_then(|slot| {
+                    let board = &self.side(enemy).board;
+                    self.rules
+                        .lane_slots(self.rules.lane(slot))
+                        .find(|&slot| board.get(slot).is_some_and(Option::is_some))
+                })
+                .map(|slot| (enemy, slot))
+                .into_iter()
+                .collect(),
+            Target::FriendlyCreatures => occupied(friendly).collect(),
+            Target::EnemyCreatures => occupied(enemy).collect(),
+            Target::AllCreatures => occupied(friendly).chain(occupied(enemy)).collect(),
+        };
+        candidates
+            .into_iter()
+            .filter(|&(side, slot)| self.side(side).board.get(slot).is_some_and(Option::is_some))
+            .collect()
+    }
+
+    // The creatures a target refers to
+    fn target_units(&mut self, source: Source, target: Target) -> Vec<&mut Unit> {
+        let slots = self.target_slots(source, target);
+        let (player, opponent) = (&mut self.player, &mut self.opponent);
+        let mut units = Vec::new();
+        for (side, board) in [
+            (PlayerSide::Player, &mut player.board),
+            (PlayerSide::Opponent, &mut opponent.board),
+        ] {
+            for (slot, unit) in board.iter_mut().enumerate() {
+                if slots.contains(&(side, slot))
+                    && let Some(unit) = unit
+                {
+                    units.push(unit);
+                }
+            }
+        }
+        units
+    }
+
+    // Everything `controller` could pick for a chosen target
+    pub fn legal_targets(&self, controller: PlayerSide, target: Target) -> Vec<TargetChoice> {
+        let creatures = |side: PlayerSide| {
+            self.side(side)
+                .board
+                .iter()
+                .enumerate()
+                .filter(move |(_, unit)| unit.as_ref().is_some_and(|unit| unit.can_be_chosen_by(side, controller)))
+                .map(move |(slot, _)| TargetChoice::Creature { side, slot })
+        };
+        let players = [TargetChoice::Player(controller), TargetChoice::Player(controller.other())];
+
+        match target {
+            Target::ChosenCreature => creatures(controller).chain(creatures(controller.other())).collect(),
+            Target::ChosenEnemyCreature => creatures(controller.other()).collect(),
+            Target::ChosenFriendlyCreature => creatures(controller).collect(),
+            Target::ChosenPlayer => players.to_vec(),
+            Target::ChosenAny => players
+                .into_iter()
+                .chain(creatures(controller))
+                .chain(creatures(controller.other()))
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    // The ways to satisfy `requirement` for the player with priority. With no legal target, creatures
+    // can still be played (their chosen effects just do nothing) but spells and abilities can't.
+    pub(crate) fn target_options(&self, requirement: Option<Target>, optional: bool) -> Vec<Option<TargetChoice>> {
+        let Some(requirement) = requirement else {
+            return vec![None];
+        };
+        let legal = self.legal_targets(self.priority, requirement);
+        if legal.is_empty() {
+            return if optional { vec![None] } else { Vec::new() };
+        }
+        legal.into_iter().map(Some).collect()
+    }
+
+    // Whether `chosen` satisfies `requirement`, following the rules of `target_options`
+    pub(crate) fn check_target(
+        &self,
+        requirement: Option<Target>,
+        chosen: Option<TargetChoice>,
+        optional: bool,
+    ) -> Result<(), PlayError> {
+        let options = self.target_options(requirement, optional);
+        match chosen {
+            _ if requirement.is_none() => Ok(()),
+            chosen if options.contains(&chosen) => Ok(()),
+            Some(_) => Err(PlayError::IllegalTarget),
+            None => Err(PlayError::TargetRequired),
+        }
+    }
+
+    // Move destroyed creatures to their owner's graveyard and put their death triggers on the
+    // stack, so they resolve in the order the creatures died
+    pub(crate) fn remove_destroyed(&mut self) {
+        let mut dead = Vec::new();
+        for side in [self.active, self.active.other()] {
+            let player = self.side_mut(side);
+            for (slot, entry) in player.board.iter_mut().enumerate() {
+                if entry.as_ref().is_some_and(Unit::is_destroyed)
+                    && let Some(unit) = entry.take()
+                {
+                    player.graveyard.push(unit.card.clone());
+                    dead.push((side, slot, unit.card));
+                }
+            }
+        }
+
+        for (side, slot, card) in dead.into_iter().rev() {
+            self.push_triggers(&card, side, Some(slot), None, Trigger::OnDeath);
+        }
+    }
+
+    // Put a trigger of each of a side's creatures on the stack, so they resolve in slot order
+    pub(crate) fn fire_board_trigger(&mut self, side: PlayerSide, trigger: Trigger) {
+        let cards: Vec<_> = self
+            .side(side)
+            .board
+            .iter()
+            .enumerate()
+            .filter_map(|(slot, unit)| unit.as_ref().map(|unit| (slot, unit.card.clone())))
+            .collect();
+
+        for (slot, card) in cards.into_iter().rev() {
+            self.push_triggers(&card, side, Some(slot), None, trigger);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::card::{CardId, CardInstance};
+    use crate::state::STARTING_LIFE;
+    use crate::state::tests::{creature, match_with_hand, place, spell};
+
+    fn unit(state: &MatchState, side: PlayerSide, slot: usize) -> &Unit {
+        state.side(side).board[slot].as_ref().unwrap()
+    }
+
+    // A creature with the given abilities and no keywords
+    fn creature_with(id: &str, attack: u32, health: u32, abilities: Vec<Ability>) -> CardData {
+        CardData { abilities, ..creature(id, 1, attack, health, &[]) }
+    }
+
+    fn card(id: u32, data: CardData) -> CardInstance {
+        CardInstance { id: CardId(id), data: Arc::new(data) }
+    }
+
+    #[test]
+    fn draw_takes_from_the_top_of_the_deck_until_it_runs_out() {
+        let mut state = match_with_hand(vec![spell("study", 1, vec![Effect::Draw { count: 3 }])], 1);
+        state.player.deck = vec![card(10, creature("bottom", 1, 1, 1, &[])), card(11, creature("top", 1, 1, 1, &[]))];
+
+        state.play(0, 0, None).unwrap();
+        state.resolve_stack();
+
+        let hand: Vec<_> = state.player.hand.iter().map(|card| card.data.id.as_str()).collect();
+        assert_eq!(hand, vec!["top", "bottom"]);
+        assert!(state.player.deck.is_empty());
+        assert_eq!(state.outcome, None);
+    }
+
+    #[test]
+    fn discard_takes_the_most_recently_drawn_cards() {
+        let mut state = match_with_hand(vec![spell("rot", 1, vec![Effect::Discard { count: 2, target: Target::Opponent }])], 1);
+        for (id, name) in [(10, "first"), (11, "second"), (12, "third")] {
+            state.opponent.hand.push(card(id, creature(name, 1, 1, 1, &[])));
+        }
+
+        state.play(0, 0, None).unwrap();
+        state.resolve_stack();
+
+        assert_eq!(state.opponent.hand.len(), 1);
+        assert_eq!(state.opponent.hand[0].data.id, "first");
+        let graveyard: Vec<_> = state.opponent.graveyard.iter().map(|card| card.data.id.as_str()).collect();
+        assert_eq!(graveyard, vec!["third", "second"]);
+    }
+
+    #[test]
+    fn heal_stops_at_a_creatures_maximum_health_but_not_a_players_life() {
+        let mend = spell(
+            "mend",
+            1,
+            vec![
+                Effect::Heal { amount: 5, target: Target::FriendlyCreatures },
+                Effect::Heal { amount: 5, target: Target::You },
+            ],
+        );
+        let mut state = match_with_hand(vec![mend], 1);
+        place(&mut state, PlayerSide::Player, 0, creature("bear", 2, 2, 4, &[]));
+        state.player.board[0].as_mut().unwrap().health = 1;
+
+        state.play(0, 0, None).unwrap();
+        state.resolve_stack();
+
+        assert_eq!(unit(&state, PlayerSide::Player, 0).health, 4);
+        assert_eq!(state.player.life, STARTING_LIFE + 5);
+    }
+
+    #[test]
+    fn a_negative_buff_can_destroy_a_creature() {
+        let wither = spell("wither", 1, vec![Effect::Buff { attack: -1, health: -2, target: Target::EnemyCreatures }]);
+        let mut state = match_with_hand(vec![wither], 1);
+        place(&mut state, PlayerSide::Opponent, 0, creature("bear", 2, 2, 2, &[]));
+        place(&mut state, PlayerSide::Opponent, 1, creature("oak", 4, 3, 5, &[]));
+
+        state.play(0, 0, None).unwrap();
+        state.resolve_stack();
+
+        assert!(state.opponent.board[0].is_none());
+        assert_eq!(state.opponent.graveyard[0].data.id, "bear");
+        let oak = unit(&state, PlayerSide::Opponent, 1);
+        assert_eq!((oak.attack, oak.health, oak.max_health), (2, 3, 3));
+    }
+
+    #[test]
+    fn destroy_kills_the_chosen_creature_whatever_its_health() {
+        let doom = spell("doom", 1, vec![Effect::Destroy { target: Target::ChosenEnemyCreature }]);
+        let mut state = match_with_hand(vec![doom], 1);
+        place(&mut state, PlayerSide::Opponent, 0, creature("bear", 2, 2, 2, &[]));
+        place(&mut state, PlayerSide::Opponent, 1, creature("giant", 8, 8, 99, &[]));
+
+        let target = TargetChoice::Creature { side: PlayerSide::Opponent, slot: 1 };
+        state.play(0, 0, Some(target)).unwrap();
+        state.resolve_stack();
+
+        assert!(state.opponent.board[0].is_some());
+        assert!(state.opponent.board[1].is_none());
+        assert_eq!(state.opponent.graveyard[0].data.id, "giant");
+    }
+
+    #[test]
+    fn return_to_hand_sends_a_creature_back_to_its_owner_without_its_buffs() {
+        let bounce = spell("bounce", 1, vec![Effect::ReturnToHand { target: Target::ChosenCreature }]);
+        let mut state = match_with_hand(vec![bounce], 1);
+        place(&mut state, PlayerSide::Opponent, 2, creature("bear", 2, 2, 2, &[]));
+        let bear = state.opponent.board[2].as_mut().unwrap();
+        bear.attack = 5;
+        bear.health = 1;
+
+        let target = TargetChoice::Creature { side: PlayerSide::Opponent, slot: 2 };
+        state.play(0, 0, Some(target)).unwrap();
+        state.resolve_stack();
+
+        assert!(state.opponent.board[2].is_none());
+        assert!(state.opponent.graveyard.is_empty());
+        assert_eq!(state.opponent.hand.len(), 1);
+        let replayed = Unit::new(state.opponent.hand[0].clone()).unwrap();
+        assert_eq!((replayed.attack, replayed.health), (2, 2));
+    }
+
+    #[test]
+    fn death_triggers_fire_when_the_creature_dies() {
+        let martyr = creature_with(
+            "martyr",
+            1,
+            1,
+            vec![Ability::Triggered {
+                trigger: Trigger::OnDeath,
+                effects: vec![Effect::Damage { amount: 3, target: Target::Opponent }],
+            }],
+        );
+        let sweep = spell("sweep", 1, vec![Effect::Damage { amount: 1, target: Target::AllCreatures }]);
+        let mut state = match_with_hand(vec![sweep], 1);
+        place(&mut state, PlayerSide::Player, 0, martyr);
+
+        state.play(0, 0, None).unwrap();
+        state.resolve_stack();
+
+        assert!(state.player.board[0].is_none());
+        assert_eq!(state.opponent.life, STARTING_LIFE - 3);
+    }
+
+    #[test]
+    fn turn_triggers_fire_only_on_their_controllers_turn() {
+        let abilities = vec![
+            Ability::Triggered {
+                trigger: Trigger::StartOfTurn,
+                effects: vec![Effect::Heal { amount: 2, target: Target::You }],
+            },
+            Ability::Triggered {
+                trigger: Trigger::EndOfTurn,
+                effects: vec![Effect::Damage { amount: 1, target: Target::Opponent }],
+            },
+        ];
+        let mut state = match_with_hand(Vec::new(), 0);
+        place(&mut state, PlayerSide::Player, 0, creature_with("shrine", 0, 3, abilities.clone()));
+        place(&mut state, PlayerSide::Opponent, 0, creature_with("altar", 0, 3, abilities));
+
+        state.start_turn();
+        assert_eq!(state.stack.len(), 1);
+        state.resolve_stack();
+        assert_eq!(state.player.life, STARTING_LIFE + 2);
+
+        state.end_turn();
+        assert_eq!(state.stack.len(), 1);
+        state.resolve_stack();
+        assert_eq!(state.opponent.life, STARTING_LIFE - 1);
+        assert_eq!(state.player.life, STARTING_LIFE + 2);
+    }
+
+    #[test]
+    fn activated_abilities_cost_mana_and_exhaust_needs_a_ready_creature() {
+        let ping = vec![Ability::Activated {
+            mana: 2,
+            exhaust: true,
+            effects: vec![Effect::Damage { amount: 1, target: Target::Opponent }],
+        }];
+        let mut state = match_with_hand(Vec::new(), 3);
+        place(&mut state, PlayerSide::Player, 0, creature_with("archer", 1, 1, ping.clone()));
+        place(&mut state, PlayerSide::Player, 1, creature_with("slinger", 1, 1, ping));
+        state.player.board[1].as_mut().unwrap().can_attack = false;
+
+        assert_eq!(state.check_activation(1, 0), Err(PlayError::NotReady));
+        assert_eq!(state.check_activation(0, 1), Err(PlayError::NoSuchAbility(1)));
+        assert_eq!(state.check_activation(2, 0), Err(PlayError::NoCreature(2)));
+
+        state.activate(0, 0, None).unwrap();
+        assert_eq!(state.player.mana.current, 1);
+        assert!(!unit(&state, PlayerSide::Player, 0).can_attack);
+        state.resolve_stack();
+        assert_eq!(state.opponent.life, STARTING_LIFE - 1);
+
+        state.player.board[1].as_mut().unwrap().can_attack = true;
+        assert_eq!(state.check_activation(1, 0), Err(PlayError::NotEnoughMana { cost: 2, available: 1 }));
+    }
+
+    #[test]
+    fn activated_abilities_can_be_used_once_per_turn() {
+        let tap = vec![Ability::Activated {
+            mana: 0,
+            exhaust: false,
+            effects: vec![Effect::Heal { amount: 1, target: Target::You }],
+        }];
+        let mut state = match_with_hand(Vec::new(), 0);
+        place(&mut state, PlayerSide::Player, 0, creature_with("cleric", 1, 1, tap));
+
+        state.activate(0, 0, None).unwrap();
+        state.resolve_stack();
+        assert_eq!(state.activate(0, 0, None), Err(PlayError::AbilityUsed));
+        assert_eq!(state.player.life, STARTING_LIFE + 1);
+
+        state.start_turn();
+        state.activate(0, 0, None).unwrap();
+        state.resolve_stack();
+        assert_eq!(state.player.life, STARTING_LIFE + 2);
+    }
+}
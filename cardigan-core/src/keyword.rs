@@ -0,0 +1,245 @@
+use serde::Deserialize;
+use crate::state::{MatchState, PlayerSide, Unit};
+
+// Abilities a creature has just by naming them in its card data. Each keyword's rule lives
+// here, once; combat and the play rules ask these helpers instead of looking at cards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+pub enum Keyword {
+    // Also blocks attacks in the lanes next to it that nothing else blocks
+    Guard,
+    // Can attack the turn it is played
+    Haste,
+    // Can only be blocked by creatures with Flying
+    Flying,
+    // Combat damage it deals also heals its controller
+    Lifesteal,
+    // Any combat damage it deals to a creature destroys that creature
+    Deathtouch,
+    // Can't be chosen as a target by the opponent's spells and abilities
+    Elusive,
+}
+
+impl Keyword {
+    pub fn name(self) -> &'static str {
+        match self {
+            Keyword::Guard => "Guard",
+            Keyword::Haste => "Haste",
+            Keyword::Flying => "Flying",
+            Keyword::Lifesteal => "Lifesteal",
+            Keyword::Deathtouch => "Deathtouch",
+            Keyword::Elusive => "Elusive",
+        }
+    }
+}
+
+impl Unit {
+    pub fn has(&self, keyword: Keyword) -> bool {
+        self.card.data.keywords.contains(&keyword)
+    }
+
+    // Haste: whether the creature can attack as soon as it is played
+    pub(crate) fn ready_on_play(&self) -> bool {
+        self.has(Keyword::Haste)
+    }
+
+    // Flying: whether this creature can block `attacker`
+    pub fn can_block(&self, attacker: &Unit) -> bool {
+        !self.is_destroyed() && (!attacker.has(Keyword::Flying) || self.has(Keyword::Flying))
+    }
+
+    // Elusive: whether `chooser` may choose this creature, which `owner` controls, as a target
+    pub fn can_be_chosen_by(&self, owner: PlayerSide, chooser: PlayerSide) -> bool {
+        owner == chooser || !self.has(Keyword::Elusive)
+    }
+
+    // Deal this creature's combat damage to `target`; Deathtouch makes any damage lethal
+    pub(crate) fn strike(&self, target: &mut Unit) {
+        if self.attack == 0 {
+            return;
+        }
+        target.health = target.health.saturating_sub(i32::try_from(self.attack).unwrap_or(i32::MAX));
+        if self.has(Keyword::Deathtouch) {
+            target.health = target.health.min(0);
+        }
+    }
+
+    // Whether this creature's combat damage alone would destroy `target`
+    pub fn kills(&self, target: &Unit) -> bool {
+        let mut target = target.clone();
+        self.strike(&mut target);
+        target.is_destroyed()
+    }
+}
+
+impl MatchState {
+    // Which of `defender`'s creatures blocks `attacker` coming down the lane of `slot`: the
+    // frontmost creature in that lane that can, or else a Guard in a neighbouring lane. None
+    // means the attack hits the defending player.
+    pub fn blocker_for(&self, defender: PlayerSide, slot: usize, attacker: &Unit) -> Option<usize> {
+        let board = &self.side(defender).board;
+        let can_block = |slot: usize| board.get(slot).and_then(Option::as_ref).is_some_and(|unit| unit.can_block(attacker));
+
+        let lane = self.rules.lane(slot);
+        if let Some(blocker) = self.rules.lane_slots(lane).find(|&slot| can_block(slot)) {
+            return Some(blocker);
+        }
+
+        [lane.checked_sub(1), Some(lane + 1).filter(|&next| next < self.rules.board_slots)]
+            .into_iter()
+            .flatten()
+            .flat_map(|lane| self.rules.lane_slots(lane))
+            .find(|&next| can_block(next) && board[next].as_ref().is_some_and(|unit| unit.has(Keyword::Guard)))
+    }
+
+    // Lifesteal: `side` gains the combat damage its creature `unit` just dealt
+    pub(crate) fn lifesteal(&mut self, side: PlayerSide, unit: &Unit, dealt: u32) {
+        if unit.has(Keyword::Lifesteal) {
+            let player = self.side_mut(side);
+            player.life = player.life.saturating_add(i32::try_from(dealt).unwrap_or(i32::MAX));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::card::{CardData, CardId, CardInstance};
+    use crate::effect::{Target, TargetChoice};
+    use crate::state::STARTING_LIFE;
+    use crate::state::tests::{creature, match_with_hand, place};
+
+    fn unit(state: &MatchState, side: PlayerSide, slot: usize) -> &Unit {
+        state.side(side).board[slot].as_ref().unwrap()
+    }
+
+    // A creature on its own, off any board
+    fn creature_unit(card: CardData) -> Unit {
+        Unit::new(CardInstance { id: CardId(0), data: card.into() }).unwrap()
+    }
+
+    #[test]
+    fn guard_blocks_attacks_in_the_next_lanes() {
+        let mut state = match_with_hand(Vec::new(), 0);
+        place(&mut state, PlayerSide::Player, 2, creature("bear", 2, 2, 2, &[]));
+        place(&mut state, PlayerSide::Player, 4, creature("wolf", 2, 2, 2, &[]));
+        place(&mut state, PlayerSide::Opponent, 1, creature("sentry", 2, 0, 5, &[Keyword::Guard]));
+
+        // The sentry covers the lane next to it, but not two lanes over
+        let bear = unit(&state, PlayerSide::Player, 2).clone();
+        assert_eq!(state.blocker_for(PlayerSide::Opponent, 2, &bear), Some(1));
+        let wolf = unit(&state, PlayerSide::Player, 4).clone();
+        assert_eq!(state.blocker_for(PlayerSide::Opponent, 4, &wolf), None);
+
+        state.resolve_combat(&[2, 4]);
+        assert_eq!(unit(&state, PlayerSide::Opponent, 1).health, 3);
+        assert_eq!(state.opponent.life, STARTING_LIFE - 2);
+    }
+
+    #[test]
+    fn guard_does_not_take_over_a_lane_with_its_own_blocker() {
+        let mut state = match_with_hand(Vec::new(), 0);
+        place(&mut state, PlayerSide::Player, 2, creature("bear", 2, 2, 2, &[]));
+        place(&mut state, PlayerSide::Opponent, 1, creature("sentry", 2, 0, 5, &[Keyword::Guard]));
+        place(&mut state, PlayerSide::Opponent, 2, creature("wall", 2, 0, 5, &[]));
+
+        let bear = unit(&state, PlayerSide::Player, 2).clone();
+        assert_eq!(state.blocker_for(PlayerSide::Opponent, 2, &bear), Some(2));
+    }
+
+    #[test]
+    fn haste_is_ready_on_play() {
+        let mut state = match_with_hand(vec![creature("raider", 1, 2, 1, &[Keyword::Haste]), creature("bear", 1, 2, 2, &[])], 2);
+
+        state.play(0, 0, None).unwrap();
+        state.play(0, 1, None).unwrap();
+
+        assert!(unit(&state, PlayerSide::Player, 0).ready_on_play());
+        assert!(unit(&state, PlayerSide::Player, 0).can_attack);
+        assert!(!unit(&state, PlayerSide::Player, 1).can_attack);
+        assert_eq!(state.ready_attackers(), vec![0]);
+    }
+
+    #[test]
+    fn flying_can_only_be_blocked_by_flying() {
+        let mut state = match_with_hand(Vec::new(), 0);
+        place(&mut state, PlayerSide::Player, 0, creature("whelp", 1, 2, 1, &[Keyword::Flying]));
+        place(&mut state, PlayerSide::Opponent, 0, creature("wall", 2, 0, 5, &[]));
+        let whelp = unit(&state, PlayerSide::Player, 0).clone();
+        let wall = unit(&state, PlayerSide::Opponent, 0).clone();
+
+        assert!(!wall.can_block(&whelp));
+        assert!(whelp.can_block(&wall));
+        assert_eq!(state.blocker_for(PlayerSide::Opponent, 0, &whelp), None);
+
+        place(&mut state, PlayerSide::Opponent, 0, creature("hawk", 2, 1, 1, &[Keyword::Flying]));
+        assert!(unit(&state, PlayerSide::Opponent, 0).can_block(&whelp));
+        assert_eq!(state.blocker_for(PlayerSide::Opponent, 0, &whelp), Some(0));
+    }
+
+    #[test]
+    fn lifesteal_heals_its_controller() {
+        let mut state = match_with_hand(Vec::new(), 0);
+        place(&mut state, PlayerSide::Player, 0, creature("leech", 2, 3, 3, &[Keyword::Lifesteal]));
+        place(&mut state, PlayerSide::Player, 1, creature("bat", 2, 2, 3, &[Keyword::Lifesteal]));
+        place(&mut state, PlayerSide::Opponent, 1, creature("wall", 2, 0, 5, &[]));
+        state.player.life = 10;
+
+        // Into an empty lane and into a blocker, both heal
+        state.resolve_combat(&[0, 1]);
+
+        assert_eq!(state.player.life, 15);
+        assert_eq!(state.opponent.life, STARTING_LIFE - 3);
+    }
+
+    #[test]
+    fn deathtouch_kills_on_any_damage() {
+        let mut state = match_with_hand(Vec::new(), 0);
+        place(&mut state, PlayerSide::Player, 0, creature("asp", 1, 1, 1, &[Keyword::Deathtouch]));
+        place(&mut state, PlayerSide::Opponent, 0, creature("oak", 5, 2, 8, &[]));
+        let asp = unit(&state, PlayerSide::Player, 0).clone();
+        let oak = unit(&state, PlayerSide::Opponent, 0).clone();
+
+        assert!(asp.kills(&oak));
+        assert!(!oak.kills(&creature_unit(creature("giant", 5, 1, 9, &[]))));
+
+        state.resolve_combat(&[0]);
+        assert!(state.opponent.board[0].is_none());
+        assert!(state.player.board[0].is_none());
+    }
+
+    #[test]
+    fn deathtouch_needs_damage_to_be_dealt() {
+        let asp = creature_unit(creature("asp", 1, 0, 1, &[Keyword::Deathtouch]));
+        let mut oak = creature_unit(creature("oak", 5, 2, 8, &[]));
+
+        asp.strike(&mut oak);
+
+        assert_eq!(oak.health, 8);
+        assert!(!asp.kills(&oak));
+    }
+
+    #[test]
+    fn elusive_cannot_be_chosen_by_enemy_effects() {
+        let mut state = match_with_hand(Vec::new(), 0);
+        place(&mut state, PlayerSide::Opponent, 0, creature("eel", 2, 2, 2, &[Keyword::Elusive]));
+        place(&mut state, PlayerSide::Opponent, 1, creature("crab", 2, 2, 2, &[]));
+        let eel = unit(&state, PlayerSide::Opponent, 0);
+
+        assert!(!eel.can_be_chosen_by(PlayerSide::Opponent, PlayerSide::Player));
+        assert!(eel.can_be_chosen_by(PlayerSide::Opponent, PlayerSide::Opponent));
+
+        let enemy = state.legal_targets(PlayerSide::Player, Target::ChosenEnemyCreature);
+        assert_eq!(enemy, vec![TargetChoice::Creature { side: PlayerSide::Opponent, slot: 1 }]);
+        let friendly = state.legal_targets(PlayerSide::Opponent, Target::ChosenFriendlyCreature);
+        assert_eq!(friendly.len(), 2);
+    }
+
+    #[test]
+    fn keywords_are_read_from_the_card() {
+        let unit = creature_unit(creature("warden", 3, 2, 4, &[Keyword::Guard, Keyword::Lifesteal]));
+
+        assert!(unit.has(Keyword::Guard));
+        assert!(unit.has(Keyword::Lifesteal));
+        assert!(!unit.has(Keyword::Flying));
+    }
+}
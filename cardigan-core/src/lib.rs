@@ -0,0 +1,24 @@
+// Rules of a Cardigan match with no engine attached: card definitions, the match state, legal
+// move generation and action application. The game renders this state; the AI, tools and
+// tests can drive it without a window.
+
+mod card;
+mod effect;
+mod keyword;
+mod mana;
+mod mulligan;
+mod script;
+mod stack;
+mod state;
+
+pub use card::{CardData, CardId, CardInstance, CardStats};
+pub use effect::{Ability, Effect, Target, TargetChoice, Trigger};
+pub use keyword::Keyword;
+pub use mana::{ManaPool, MAX_MANA};
+pub use mulligan::MulliganRule;
+pub use script::ScriptError;
+pub use stack::{StackItem, StackKind};
+pub use state::{
+    Action, MatchEndReason, MatchRules, MatchState, Outcome, PileKind, PlayError, PlayerSide,
+    PlayerState, Unit, STARTING_LIFE,
+};
@@ -0,0 +1,29 @@
+// Mana pools never grow past this
+pub const MAX_MANA: u32 = 10;
+
+// A player's mana: `max` grows by one each turn and `current` refills to it
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ManaPool {
+    pub current: u32,
+    pub max: u32,
+}
+
+impl ManaPool {
+    pub fn can_pay(&self, cost: u32) -> bool {
+        cost <= self.current
+    }
+
+    // Spend mana for a play; returns false (and spends nothing) if the pool is short
+    pub fn spend(&mut self, cost: u32) -> bool {
+        if !self.can_pay(cost) {
+            return false;
+        }
+        self.current -= cost;
+        true
+    }
+
+    pub fn refill(&mut self) {
+        self.max = (self.max + 1).min(MAX_MANA);
+        self.current = self.max;
+    }
+}
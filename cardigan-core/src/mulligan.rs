@@ -0,0 +1,154 @@
+use rand::Rng;
+use rand::seq::SliceRandom;
+use crate::state::{MatchState, PlayError, PlayerSide};
+
+// How a player may redraw their opening hand, once, before the first turn
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MulliganRule {
+    // Keep the whole hand, or shuffle all of it back and draw a new one of the same size
+    Full,
+    // Shuffle back any of the cards and draw that many replacements
+    #[default]
+    Partial,
+}
+
+impl MatchState {
+    // Both players draw their opening hands
+    pub fn deal_opening_hands(&mut self) {
+        for side in [PlayerSide::Player, PlayerSide::Opponent] {
+            for _ in 0..self.rules.opening_hand {
+                self.draw(side);
+            }
+        }
+    }
+
+    // `side` shuffles the cards at `hand_indexes` back into their deck and draws as many new
+    // ones. Under the full rule that has to be the whole hand or none of it. Keeping the hand
+    // is a mulligan of no cards; either way each player decides once.
+    pub fn mulligan(&mut self, side: PlayerSide, hand_indexes: &[usize], rng: &mut impl Rng) -> Result<(), PlayError> {
+        if self.turn_number > 0 || self.side(side).mulligan_done {
+            return Err(PlayError::MulliganOver);
+        }
+
+        let mut hand_indexes = hand_indexes.to_vec();
+        hand_indexes.sort_unstable();
+        hand_indexes.dedup();
+        let hand_size = self.side(side).hand.len();
+        if let Some(&index) = hand_indexes.iter().find(|&&index| index >= hand_size) {
+            return Err(PlayError::NotInHand(index));
+        }
+        let whole_hand_or_none = hand_indexes.is_empty() || hand_indexes.len() == hand_size;
+        if self.rules.mulligan == MulliganRule::Full && !whole_hand_or_none {
+            return Err(PlayError::WholeHandOnly);
+        }
+
+        let player = self.side_mut(side);
+        for &index in hand_indexes.iter().rev() {
+            let card = player.hand.remove(index);
+            player.deck.push(card);
+        }
+        player.deck.shuffle(rng);
+        player.mulligan_done = true;
+        for _ in 0..hand_indexes.len() {
+            self.draw(side);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+    use super::*;
+    use crate::card::{CardData, CardId, CardInstance};
+    use crate::state::MatchRules;
+    use crate::state::tests::creature;
+
+    fn deck(prefix: &str) -> Vec<CardData> {
+        (0..10).map(|index| creature(&format!("{prefix}{index}"), 1, 1, 1, &[])).collect()
+    }
+
+    fn dealt(rules: MatchRules) -> MatchState {
+        let mut state = MatchState::new(deck("p"), deck("o"), rules);
+        state.deal_opening_hands();
+        state
+    }
+
+    fn names(cards: &[CardInstance]) -> Vec<&str> {
+        cards.iter().map(|card| card.data.id.as_str()).collect()
+    }
+
+    #[test]
+    fn opening_hands_come_off_the_top_of_each_deck() {
+        let state = dealt(MatchRules::default());
+
+        assert_eq!(names(&state.player.hand), vec!["p9", "p8", "p7", "p6"]);
+        assert_eq!(names(&state.opponent.hand), vec!["o9", "o8", "o7", "o6"]);
+        assert_eq!(state.player.deck.len(), 6);
+    }
+
+    #[test]
+    fn the_same_seed_deals_the_same_hands_and_mulligans() {
+        let deal = |seed: u64| {
+            let mut rng = StdRng::seed_from_u64(seed);
+            let mut player_deck = deck("p");
+            player_deck.shuffle(&mut rng);
+            let mut state = MatchState::new(player_deck, deck("o"), MatchRules::default());
+            state.deal_opening_hands();
+            let opening: Vec<CardId> = state.player.hand.iter().map(|card| card.id).collect();
+            state.mulligan(PlayerSide::Player, &[0, 1], &mut rng).unwrap();
+            let after: Vec<CardId> = state.player.hand.iter().map(|card| card.id).collect();
+            (opening, after)
+        };
+
+        assert_eq!(deal(42), deal(42));
+        assert_ne!(deal(42), deal(43));
+    }
+
+    #[test]
+    fn a_partial_mulligan_keeps_the_hand_size_and_shuffles_the_cards_back() {
+        let mut state = dealt(MatchRules::default());
+        let kept = [state.player.hand[1].id, state.player.hand[3].id];
+
+        state.mulligan(PlayerSide::Player, &[2, 0], &mut StdRng::seed_from_u64(1)).unwrap();
+
+        assert_eq!(state.player.hand.len(), 4);
+        assert_eq!(state.player.deck.len(), 6);
+        assert_eq!([state.player.hand[0].id, state.player.hand[1].id], kept);
+        // No card was lost or duplicated on the way
+        let mut all: Vec<u32> = state.player.hand.iter().chain(&state.player.deck).map(|card| card.id.0).collect();
+        all.sort_unstable();
+        assert_eq!(all, (1..=10).collect::<Vec<_>>());
+        assert!(state.player.mulligan_done);
+        assert!(!state.opponent.mulligan_done);
+    }
+
+    #[test]
+    fn the_full_rule_rejects_a_partial_pick() {
+        let rules = MatchRules { mulligan: MulliganRule::Full, ..MatchRules::default() };
+        let mut state = dealt(rules);
+        let opening: Vec<CardId> = state.player.hand.iter().map(|card| card.id).collect();
+        let mut rng = StdRng::seed_from_u64(1);
+
+        assert_eq!(state.mulligan(PlayerSide::Player, &[0, 2], &mut rng), Err(PlayError::WholeHandOnly));
+        assert!(state.player.hand.iter().map(|card| card.id).eq(opening));
+        assert!(!state.player.mulligan_done);
+
+        state.mulligan(PlayerSide::Player, &[0, 1, 2, 3], &mut rng).unwrap();
+        assert_eq!(state.player.hand.len(), 4);
+    }
+
+    #[test]
+    fn each_player_mulligans_only_once() {
+        let mut state = dealt(MatchRules::default());
+        let mut rng = StdRng::seed_from_u64(1);
+
+        state.mulligan(PlayerSide::Player, &[], &mut rng).unwrap();
+        assert_eq!(state.mulligan(PlayerSide::Player, &[0], &mut rng), Err(PlayError::MulliganOver));
+
+        // Nor once the first turn has begun
+        state.start_turn();
+        assert_eq!(state.mulligan(PlayerSide::Opponent, &[0], &mut rng), Err(PlayError::MulliganOver));
+    }
+}
@@ -0,0 +1,425 @@
+// Card effects written as Rhai scripts. A script gets a `game` handle with a small API over the
+// match and runs against a copy of the state, which replaces the real one only if the script
+// finishes without an error. Without the `scripting` feature every script fails instead.
+
+use std::fmt;
+use crate::effect::Source;
+use crate::state::MatchState;
+
+// A script that failed, kept on the match state so a front end can show it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScriptError {
+    pub path: String,
+    pub message: String,
+}
+
+impl fmt::Display for ScriptError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.path, self.message)
+    }
+}
+
+impl MatchState {
+    // Run a script effect, recording any error instead of changing the match
+    pub(crate) fn run_script(&mut self, source: Source, path: &str, script: &str) {
+        let result = if script.is_empty() {
+            Err("the script was never loaded".to_string())
+        } else {
+            engine::run(self, source, script)
+        };
+        if let Err(message) = result {
+            self.script_errors.push(ScriptError {
+                path: path.to_string(),
+                message,
+            });
+        }
+    }
+}
+
+#[cfg(not(feature = "scripting"))]
+mod engine {
+    use super::*;
+
+    pub(super) fn run(_state: &mut MatchState, _source: Source, _script: &str) -> Result<(), String> {
+        Err("this build has no scripting support (enable the `scripting` feature)".to_string())
+    }
+}
+
+#[cfg(feature = "scripting")]
+mod engine {
+    use std::cell::RefCell;
+    use std::collections::HashMap;
+    use std::rc::Rc;
+    use rhai::module_resolvers::DummyModuleResolver;
+    use rhai::{Array, Dynamic, Engine, EvalAltResult, Map, Scope, AST, INT};
+    use super::*;
+    use crate::card::CardInstance;
+    use crate::state::{PileKind, PlayerSide, Unit};
+
+    // Execution limits: a runaway script fails instead of hanging the game
+    const MAX_OPERATIONS: u64 = 100_000;
+    const MAX_CALL_LEVELS: usize = 16;
+    const MAX_EXPR_DEPTH: usize = 32;
+    const MAX_STRING_SIZE: usize = 1024;
+    const MAX_ARRAY_SIZE: usize = 256;
+    const MAX_MAP_SIZE: usize = 64;
+
+    type ScriptResult<T> = Result<T, Box<EvalAltResult>>;
+
+    thread_local! {
+        static ENGINE: Engine = new_engine();
+        // Compiled scripts by source, so AI searches don't parse them on every play
+        static COMPILED: RefCell<HashMap<String, AST>> = RefCell::new(HashMap::new());
+    }
+
+    pub(super) fn run(state: &mut MatchState, source: Source, script: &str) -> Result<(), String> {
+        let game = Game {
+            state: Rc::new(RefCell::new(state.clone())),
+            controller: source.controller,
+        };
+        let this_slot = source.slot.map_or(Dynamic::UNIT, |slot| Dynamic::from(slot as INT));
+        let mut scope = Scope::new();
+        scope.push("game", game.clone());
+        scope.push_constant("this_slot", this_slot);
+
+        ENGINE.with(|engine| {
+            COMPILED.with_borrow_mut(|compiled| {
+                if !compiled.contains_key(script) {
+                    let ast = engine.compile(script).map_err(|error| error.to_string())?;
+                    compiled.insert(script.to_string(), ast);
+                }
+                engine
+                    .run_ast_with_scope(&mut scope, &compiled[script])
+                    .map_err(|error| error.to_string())
+            })
+        })?;
+
+        *state = game.state.borrow().clone();
+        Ok(())
+    }
+
+    // The `game` handle scripts see: the match copy being changed and whose card is running
+    #[derive(Clone)]
+    struct Game {
+        state: Rc<RefCell<MatchState>>,
+        controller: PlayerSide,
+    }
+
+    impl Game {
+        // Scripts name sides from the controller's point of view
+        fn side(&self, name: &str) -> ScriptResult<PlayerSide> {
+            match name {
+                "you" => Ok(self.controller),
+                "opponent" => Ok(self.controller.other()),
+                _ => Err(format!("unknown side `{name}` (use \"you\" or \"opponent\")").into()),
+            }
+        }
+
+        fn slot(&self, side: PlayerSide, slot: INT) -> ScriptResult<usize> {
+            let slots = self.state.borrow().side(side).board.len();
+            usize::try_from(slot)
+                .ok()
+                .filter(|&slot| slot < slots)
+                .ok_or_else(|| format!("there is no slot {slot}").into())
+        }
+
+        // Change the creature in a slot
+        fn with_unit(
+            &mut self,
+            side: &str,
+            slot: INT,
+            change: impl FnOnce(&mut Unit) -> ScriptResult<()>,
+        ) -> ScriptResult<()> {
+            let side = self.side(side)?;
+            let slot = self.slot(side, slot)?;
+            let mut state = self.state.borrow_mut();
+            let unit = state.side_mut(side).board[slot]
+                .as_mut()
+                .ok_or_else(|| format!("slot {slot} is empty"))?;
+            change(unit)
+        }
+
+        // Take the creature out of a slot
+        fn take_unit(&mut self, side: &str, slot: INT) -> ScriptResult<(PlayerSide, Unit)> {
+            let side = self.side(side)?;
+            let slot = self.slot(side, slot)?;
+            let unit = self.state.borrow_mut().side_mut(side).board[slot]
+                .take()
+                .ok_or_else(|| format!("slot {slot} is empty"))?;
+            Ok((side, unit))
+        }
+    }
+
+    // Amounts and counts can't be negative
+    fn amount(value: INT) -> ScriptResult<i32> {
+        i32::try_from(value)
+            .ok()
+            .filter(|&value| value >= 0)
+            .ok_or_else(|| format!("{value} is not a valid amount").into())
+    }
+
+    // Changes to a stat, such as buffs, can be negative
+    fn stat_change(value: INT) -> ScriptResult<i32> {
+        i32::try_from(value).map_err(|_| format!("{value} is not a valid stat change").into())
+    }
+
+    // A life total or stat that overflowed fails the script rather than wrapping around
+    fn in_range<T>(value: Option<T>) -> ScriptResult<T> {
+        value.ok_or_else(|| "the result is out of range".into())
+    }
+
+    fn card_map(card: &CardInstance) -> Dynamic {
+        let mut map = Map::new();
+        map.insert("id".into(), card.data.id.clone().into());
+        map.insert("name".into(), card.data.name.clone().into());
+        map.insert("cost".into(), (card.data.cost as INT).into());
+        let tags: Array = card.data.tags.iter().cloned().map(Dynamic::from).collect();
+        map.insert("tags".into(), tags.into());
+        map.into()
+    }
+
+    fn unit_map(slot: usize, unit: &Unit) -> Dynamic {
+        let mut map = card_map(&unit.card).cast::<Map>();
+        map.insert("slot".into(), (slot as INT).into());
+        map.insert("attack".into(), (unit.attack as INT).into());
+        map.insert("health".into(), (unit.health as INT).into());
+        map.insert("max_health".into(), (unit.max_health as INT).into());
+        map.insert("can_attack".into(), unit.can_attack.into());
+        let keywords: Array = unit.card.data.keywords.iter().map(|keyword| Dynamic::from(keyword.name().to_string())).collect();
+        map.insert("keywords".into(), keywords.into());
+        map.into()
+    }
+
+    fn new_engine() -> Engine {
+        let mut engine = Engine::new();
+
+        // No imports from disk, no eval and no printing: a script only sees `game`
+        engine.set_module_resolver(DummyModuleResolver::new());
+        engine.disable_symbol("eval");
+        engine.on_print(|_| {});
+        engine.on_debug(|_, _, _| {});
+
+        engine.set_max_operations(MAX_OPERATIONS);
+        engine.set_max_call_levels(MAX_CALL_LEVELS);
+        engine.set_max_expr_depths(MAX_EXPR_DEPTH, MAX_EXPR_DEPTH);
+        engine.set_max_string_size(MAX_STRING_SIZE);
+        engine.set_max_array_size(MAX_ARRAY_SIZE);
+        engine.set_max_map_size(MAX_MAP_SIZE);
+
+        engine.register_type_with_name::<Game>("Game");
+        register_queries(&mut engine);
+        register_moves(&mut engine);
+        register_stats(&mut engine);
+        engine
+    }
+
+    // Reading the match: life, mana and every zone
+    fn register_queries(engine: &mut Engine) {
+        engine
+            .register_fn("slots", |game: &mut Game| game.state.borrow().rules.board_size() as INT)
+            .register_fn("life", |game: &mut Game, side: &str| -> ScriptResult<INT> {
+                let side = game.side(side)?;
+                Ok(game.state.borrow().side(side).life as INT)
+            })
+            .register_fn("mana", |game: &mut Game, side: &str| -> ScriptResult<INT> {
+                let side = game.side(side)?;
+                Ok(game.state.borrow().side(side).mana.current as INT)
+            })
+            .register_fn("deck_size", |game: &mut Game, side: &str| -> ScriptResult<INT> {
+                let side = game.side(side)?;
+                Ok(game.state.borrow().side(side).deck.len() as INT)
+            })
+            .register_fn("hand", |game: &mut Game, side: &str| -> ScriptResult<Array> {
+                let side = game.side(side)?;
+                Ok(game.state.borrow().side(side).hand.iter().map(card_map).collect())
+            })
+            .register_fn("graveyard", |game: &mut Game, side: &str| -> ScriptResult<Array> {
+                let side = game.side(side)?;
+                Ok(game.state.borrow().side(side).pile(PileKind::Graveyard).iter().map(card_map).collect())
+            })
+            .register_fn("exile", |game: &mut Game, side: &str| -> ScriptResult<Array> {
+                let side = game.side(side)?;
+                Ok(game.state.borrow().side(side).pile(PileKind::Exile).iter().map(card_map).collect())
+            })
+            .register_fn("board", |game: &mut Game, side: &str| -> ScriptResult<Array> {
+                let side = game.side(side)?;
+                let state = game.state.borrow();
+                let units = state.side(side).board.iter().enumerate();
+                Ok(units.filter_map(|(slot, unit)| Some(unit_map(slot, unit.as_ref()?))).collect())
+            })
+            .register_fn("unit", |game: &mut Game, side: &str, slot: INT| -> ScriptResult<Dynamic> {
+                let side = game.side(side)?;
+                let slot = game.slot(side, slot)?;
+                let state = game.state.borrow();
+                Ok(state.side(side).board[slot]
+                    .as_ref()
+                    .map_or(Dynamic::UNIT, |unit| unit_map(slot, unit)))
+            });
+    }
+
+    // Moving cards between zones. Creatures that leave the board lose damage and buffs.
+    fn register_moves(engine: &mut Engine) {
+        engine
+            .register_fn("draw", |game: &mut Game, side: &str, count: INT| -> ScriptResult<()> {
+                let side = game.side(side)?;
+                for _ in 0..amount(count)? {
+                    game.state.borrow_mut().draw(side);
+                }
+                Ok(())
+            })
+            .register_fn("discard", |game: &mut Game, side: &str, count: INT| -> ScriptResult<()> {
+                let side = game.side(side)?;
+                let mut state = game.state.borrow_mut();
+                let player = state.side_mut(side);
+                for _ in 0..amount(count)? {
+                    if let Some(card) = player.hand.pop() {
+                        player.graveyard.push(card);
+                    }
+                }
+                Ok(())
+            })
+            .register_fn("return_to_hand", |game: &mut Game, side: &str, slot: INT| -> ScriptResult<()> {
+                let (side, unit) = game.take_unit(side, slot)?;
+                game.state.borrow_mut().side_mut(side).hand.push(unit.card);
+                Ok(())
+            })
+            .register_fn("exile_unit", |game: &mut Game, side: &str, slot: INT| -> ScriptResult<()> {
+                let (side, unit) = game.take_unit(side, slot)?;
+                game.state.borrow_mut().side_mut(side).exile.push(unit.card);
+                Ok(())
+            })
+            .register_fn("move_unit", |game: &mut Game, side: &str, from: INT, to: INT| -> ScriptResult<()> {
+                let to_side = game.side(side)?;
+                let to = game.slot(to_side, to)?;
+                if game.state.borrow().side(to_side).board[to].is_some() {
+                    return Err(format!("slot {to} is taken").into());
+                }
+                let (side, unit) = game.take_unit(side, from)?;
+                game.state.borrow_mut().side_mut(side).board[to] = Some(unit);
+                Ok(())
+            });
+    }
+
+    // Changing life totals and creature stats. Creatures brought to 0 health are destroyed
+    // (with their death triggers) once the script finishes.
+    fn register_stats(engine: &mut Engine) {
+        engine
+            .register_fn("damage_player", |game: &mut Game, side: &str, value: INT| -> ScriptResult<()> {
+                let (side, value) = (game.side(side)?, amount(value)?);
+                let mut state = game.state.borrow_mut();
+                let player = state.side_mut(side);
+                player.life = in_range(player.life.checked_sub(value))?;
+                Ok(())
+            })
+            .register_fn("heal_player", |game: &mut Game, side: &str, value: INT| -> ScriptResult<()> {
+                let (side, value) = (game.side(side)?, amount(value)?);
+                let mut state = game.state.borrow_mut();
+                let player = state.side_mut(side);
+                player.life = in_range(player.life.checked_add(value))?;
+                Ok(())
+            })
+            .register_fn("damage", |game: &mut Game, side: &str, slot: INT, value: INT| -> ScriptResult<()> {
+                let value = amount(value)?;
+                game.with_unit(side, slot, |unit| {
+                    unit.health = in_range(unit.health.checked_sub(value))?;
+                    Ok(())
+                })
+            })
+            .register_fn("heal", |game: &mut Game, side: &str, slot: INT, value: INT| -> ScriptResult<()> {
+                let value = amount(value)?;
+                game.with_unit(side, slot, |unit| {
+                    unit.health = in_range(unit.health.checked_add(value))?.min(unit.max_health);
+                    Ok(())
+                })
+            })
+            .register_fn("destroy", |game: &mut Game, side: &str, slot: INT| -> ScriptResult<()> {
+                game.with_unit(side, slot, |unit| {
+                    unit.health = unit.health.min(0);
+                    Ok(())
+                })
+            })
+            .register_fn(
+                "buff",
+                |game: &mut Game, side: &str, slot: INT, attack: INT, health: INT| -> ScriptResult<()> {
+                    let (attack, health) = (stat_change(attack)?, stat_change(health)?);
+                    game.with_unit(side, slot, |unit| {
+                        // Attack stops at 0 on the way down
+                        unit.attack = match unit.attack.checked_add_signed(attack) {
+                            None if attack < 0 => 0,
+                            attack => in_range(attack)?,
+                        };
+                        unit.max_health = in_range(unit.max_health.checked_add(health))?;
+                        unit.health = in_range(unit.health.checked_add(health))?;
+                        Ok(())
+                    })
+                },
+            )
+            .register_fn(
+                "set_stats",
+                |game: &mut Game, side: &str, slot: INT, attack: INT, health: INT| -> ScriptResult<()> {
+                    let (attack, health) = (amount(attack)?, amount(health)?);
+                    game.with_unit(side, slot, |unit| {
+                        unit.attack = attack as u32;
+                        unit.max_health = health;
+                        unit.health = health;
+                        Ok(())
+                    })
+                },
+            );
+    }
+}
+
+#[cfg(all(test, feature = "scripting"))]
+mod tests {
+    use crate::card::{CardId, CardInstance};
+    use crate::effect::Effect;
+    use crate::state::tests::{creature, match_with_hand, place, spell};
+    use crate::state::{MatchState, PlayerSide, STARTING_LIFE};
+
+    // Play a spell running `script` and resolve it
+    fn run(state: &mut MatchState, script: &str) {
+        let card = spell("script", 0, vec![Effect::Script { path: "test.rhai".to_string(), source: script.into() }]);
+        state.player.hand.push(CardInstance { id: CardId(500), data: card.into() });
+        let hand_index = state.player.hand.len() - 1;
+        state.play(hand_index, 0, None).unwrap();
+        state.resolve_stack();
+    }
+
+    #[test]
+    fn scripts_change_life_and_stats() {
+        let mut state = match_with_hand(Vec::new(), 0);
+        place(&mut state, PlayerSide::Opponent, 0, creature("bear", 2, 2, 3, &[]));
+
+        run(&mut state, r#"game.damage_player("opponent", 4); game.buff("opponent", 0, -5, 2); game.damage("opponent", 0, 1);"#);
+
+        assert!(state.script_errors.is_empty(), "{:?}", state.script_errors);
+        assert_eq!(state.opponent.life, STARTING_LIFE - 4);
+        let unit = state.opponent.board[0].as_ref().unwrap();
+        assert_eq!((unit.attack, unit.health, unit.max_health), (0, 4, 5));
+    }
+
+    #[test]
+    fn overflowing_life_fails_the_script_and_changes_nothing() {
+        let mut state = match_with_hand(Vec::new(), 0);
+
+        run(&mut state, r#"game.damage_player("opponent", 5); game.heal_player("you", 2147483647);"#);
+
+        assert_eq!(state.script_errors.len(), 1);
+        assert!(state.script_errors[0].message.contains("out of range"), "{}", state.script_errors[0]);
+        assert_eq!((state.player.life, state.opponent.life), (STARTING_LIFE, STARTING_LIFE));
+    }
+
+    #[test]
+    fn overflowing_stats_fail_the_script() {
+        let mut state = match_with_hand(Vec::new(), 0);
+        place(&mut state, PlayerSide::Player, 1, creature("bear", 2, 2, 3, &[]));
+
+        run(&mut state, r#"game.buff("you", 1, 0, 2147483647);"#);
+        run(&mut state, r#"game.buff("you", 1, 4294967296, 0);"#);
+        run(&mut state, r#"game.damage("you", 1, -1);"#);
+
+        assert_eq!(state.script_errors.len(), 3, "{:?}", state.script_errors);
+        let unit = state.player.board[1].as_ref().unwrap();
+        assert_eq!((unit.attack, unit.health, unit.max_health), (2, 3, 3));
+    }
+}
@@ -0,0 +1,233 @@
+use crate::card::CardInstance;
+use crate::effect::{Effect, Source, TargetChoice, Trigger};
+use crate::state::{MatchState, PlayError, PlayerSide};
+
+// Why something is on the stack
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StackKind {
+    // A spell being cast; the card goes to its owner's graveyard once it resolves
+    Spell,
+    // A triggered ability of a creature
+    Triggered(Trigger),
+    // An activated ability of a creature
+    Activated,
+}
+
+// A spell or ability waiting to resolve
+#[derive(Debug, Clone)]
+pub struct StackItem {
+    // The spell itself, or the creature the ability belongs to
+    pub card: CardInstance,
+    pub kind: StackKind,
+    pub controller: PlayerSide,
+    // The target picked when it was put on the stack, if it needs one
+    pub target: Option<TargetChoice>,
+    // The controller's board slot the creature was in
+    pub(crate) slot: Option<usize>,
+    pub(crate) effects: Vec<Effect>,
+}
+
+impl MatchState {
+    // Put something on the stack; the other player gets the first chance to respond
+    pub(crate) fn push(&mut self, item: StackItem) {
+        self.priority = item.controller.other();
+        self.passes = 0;
+        self.stack.push(item);
+    }
+
+    // Put the abilities `card` has for `trigger` on the stack, so they resolve in the order
+    // the card lists them
+    pub(crate) fn push_triggers(
+        &mut self,
+        card: &CardInstance,
+        controller: PlayerSide,
+        slot: Option<usize>,
+        target: Option<TargetChoice>,
+        trigger: Trigger,
+    ) {
+        let triggered: Vec<&[Effect]> = card.data.triggered(trigger).collect();
+        for effects in triggered.into_iter().rev() {
+            self.push(StackItem {
+                card: card.clone(),
+                kind: StackKind::Triggered(trigger),
+                controller,
+                target,
+                slot,
+                effects: effects.to_vec(),
+            });
+        }
+    }
+
+    // The player with priority declines to respond. Once both players have passed in a row,
+    // the top of the stack resolves.
+    pub fn pass(&mut self) -> Result<(), PlayError> {
+        if self.is_over() {
+            return Err(PlayError::MatchOver);
+        }
+        if self.stack.is_empty() {
+            return Err(PlayError::StackEmpty);
+        }
+
+        self.passes += 1;
+        if self.passes < 2 {
+            self.priority = self.priority.other();
+        } else {
+            self.resolve_top();
+        }
+        Ok(())
+    }
+
+    // Resolve everything on the stack, as if both players always passed
+    pub fn resolve_stack(&mut self) {
+        while !self.stack.is_empty() && !self.is_over() {
+            self.resolve_top();
+        }
+    }
+
+    // Resolve the top of the stack. Anything it triggers goes on top; then the player who can
+    // respond to the new top gets priority, or the active player once the stack is empty.
+    fn resolve_top(&mut self) {
+        let Some(item) = self.stack.pop() else {
+            return;
+        };
+
+        // A creature that has left the board since (other than by dying) has no slot to act
+        // from any more
+        let mut source = Source {
+            controller: item.controller,
+            slot: item.slot,
+            chosen: item.target,
+        };
+        let still_there = |slot: usize| {
+            self.side(item.controller).board[slot]
+                .as_ref()
+                .is_some_and(|unit| unit.card.id == item.card.id)
+        };
+        if item.kind != StackKind::Triggered(Trigger::OnDeath) && !source.slot.is_some_and(still_there) {
+            source.slot = None;
+        }
+
+        self.resolve_effects(source, &item.effects);
+        if item.kind == StackKind::Spell {
+            self.side_mut(item.controller).graveyard.push(item.card);
+        }
+
+        self.passes = 0;
+        self.priority = match self.stack.last() {
+            Some(top) => top.controller.other(),
+            None => self.active,
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use super::*;
+    use crate::card::{CardData, CardId};
+    use crate::effect::{Ability, Target};
+    use crate::mana::ManaPool;
+    use crate::state::STARTING_LIFE;
+    use crate::state::tests::{creature, match_with_hand, place, spell};
+
+    // Give the opponent a card to respond with and the mana to play it
+    fn give_opponent(state: &mut MatchState, card: CardData) {
+        state.opponent.mana = ManaPool { current: card.cost, max: card.cost };
+        state.opponent.hand.push(CardInstance { id: CardId(99), data: Arc::new(card) });
+    }
+
+    #[test]
+    fn priority_goes_back_and_forth_until_both_players_pass() {
+        let bolt = spell("bolt", 1, vec![Effect::Damage { amount: 2, target: Target::Opponent }]);
+        let mut state = match_with_hand(vec![bolt], 1);
+
+        state.play(0, 0, None).unwrap();
+        assert_eq!(state.priority, PlayerSide::Opponent);
+
+        state.pass().unwrap();
+        assert_eq!(state.priority, PlayerSide::Player);
+        assert_eq!(state.stack.len(), 1);
+        assert_eq!(state.opponent.life, STARTING_LIFE);
+
+        state.pass().unwrap();
+        assert!(state.stack.is_empty());
+        assert_eq!(state.opponent.life, STARTING_LIFE - 2);
+        assert_eq!(state.player.graveyard[0].data.id, "bolt");
+        assert_eq!(state.priority, PlayerSide::Player);
+        assert_eq!(state.pass(), Err(PlayError::StackEmpty));
+    }
+
+    #[test]
+    fn a_response_goes_on_top_and_resolves_first() {
+        let bolt = spell("bolt", 1, vec![Effect::Damage { amount: 2, target: Target::Opponent }]);
+        let mut state = match_with_hand(vec![bolt], 1);
+        give_opponent(&mut state, spell("salve", 1, vec![Effect::Heal { amount: 3, target: Target::You }]));
+
+        state.play(0, 0, None).unwrap();
+        state.play(0, 0, None).unwrap();
+        assert_eq!(state.stack.len(), 2);
+        assert_eq!(state.stack[1].controller, PlayerSide::Opponent);
+        assert_eq!(state.priority, PlayerSide::Player);
+
+        // Both pass: only the response resolves, and the player who can answer the bolt gets
+        // priority back
+        state.pass().unwrap();
+        state.pass().unwrap();
+        assert_eq!(state.stack.len(), 1);
+        assert_eq!(state.opponent.life, STARTING_LIFE + 3);
+        assert_eq!(state.priority, PlayerSide::Opponent);
+
+        state.pass().unwrap();
+        state.pass().unwrap();
+        assert!(state.stack.is_empty());
+        assert_eq!(state.opponent.life, STARTING_LIFE + 1);
+    }
+
+    #[test]
+    fn a_spell_does_nothing_once_its_chosen_creature_has_left_the_board() {
+        let bolt = spell("bolt", 1, vec![Effect::Damage { amount: 3, target: Target::ChosenEnemyCreature }]);
+        let mut state = match_with_hand(vec![bolt], 1);
+        place(&mut state, PlayerSide::Opponent, 0, creature("bear", 2, 2, 2, &[]));
+        give_opponent(&mut state, spell("flee", 1, vec![Effect::ReturnToHand { target: Target::ChosenFriendlyCreature }]));
+
+        let bear = TargetChoice::Creature { side: PlayerSide::Opponent, slot: 0 };
+        state.play(0, 0, Some(bear)).unwrap();
+        state.play(0, 0, Some(bear)).unwrap();
+        state.resolve_stack();
+
+        assert!(state.opponent.board[0].is_none());
+        assert_eq!(state.opponent.hand[0].data.id, "bear");
+        // Both spells still went to the graveyard
+        assert_eq!(state.opponent.graveyard.len(), 1);
+        assert_eq!(state.opponent.graveyard[0].data.id, "flee");
+        assert_eq!(state.player.graveyard[0].data.id, "bolt");
+    }
+
+    #[test]
+    fn triggers_from_a_resolving_item_go_on_the_stack() {
+        let mut martyr = creature("martyr", 1, 1, 1, &[]);
+        martyr.abilities = vec![Ability::Triggered {
+            trigger: Trigger::OnDeath,
+            effects: vec![Effect::Damage { amount: 3, target: Target::Opponent }],
+        }];
+        let sweep = spell("sweep", 1, vec![Effect::Damage { amount: 1, target: Target::AllCreatures }]);
+        let mut state = match_with_hand(vec![sweep], 1);
+        place(&mut state, PlayerSide::Player, 0, martyr);
+
+        state.play(0, 0, None).unwrap();
+        state.pass().unwrap();
+        state.pass().unwrap();
+
+        // The sweep has resolved, but the death trigger waits on the stack for both players
+        assert!(state.player.board[0].is_none());
+        assert_eq!(state.stack.len(), 1);
+        assert_eq!(state.stack[0].kind, StackKind::Triggered(Trigger::OnDeath));
+        assert_eq!(state.priority, PlayerSide::Opponent);
+        assert_eq!(state.opponent.life, STARTING_LIFE);
+
+        state.pass().unwrap();
+        state.pass().unwrap();
+        assert!(state.stack.is_empty());
+        assert_eq!(state.opponent.life, STARTING_LIFE - 3);
+    }
+}
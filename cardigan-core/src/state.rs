@@ -0,0 +1,1017 @@
+use std::sync::Arc;
+use thiserror::Error;
+use crate::card::{CardData, CardId, CardInstance};
+use crate::effect::{Ability, TargetChoice, Trigger};
+use crate::mana::ManaPool;
+use crate::mulligan::MulliganRule;
+use crate::script::ScriptError;
+use crate::stack::{StackItem, StackKind};
+
+// Life each player starts a match with
+pub const STARTING_LIFE: i32 = 20;
+
+// Above this many ready attackers, only a handful of attack combinations are offered
+const MAX_ATTACKERS_FOR_ALL_SUBSETS: usize = 6;
+
+// The two sides of the board
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash)]
+pub enum PlayerSide {
+    #[default]
+    Player,
+    Opponent,
+}
+
+impl PlayerSide {
+    pub fn other(&self) -> Self {
+        match self {
+            PlayerSide::Player => PlayerSide::Opponent,
+            PlayerSide::Opponent => PlayerSide::Player,
+        }
+    }
+}
+
+// The face-up piles each player has
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PileKind {
+    Graveyard,
+    Exile,
+}
+
+// Rules that can differ from match to match
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MatchRules {
+    // Having to draw from an empty deck loses the match
+    pub deck_out_loses: bool,
+    // Rows in each player's play area, front row first
+    pub board_rows: usize,
+    // Slots in each row, one per lane. A board's slots go row by row: slot
+    // `row * board_slots + lane`.
+    pub board_slots: usize,
+    // Cards each player draws before the first turn
+    pub opening_hand: usize,
+    pub mulligan: MulliganRule,
+    // Cards a player may hold at the end of their turn; they discard the rest
+    pub max_hand_size: usize,
+}
+
+impl Default for MatchRules {
+    fn default() -> Self {
+        Self {
+            deck_out_loses: true,
+            board_rows: 1,
+            board_slots: 5,
+            opening_hand: 4,
+            mulligan: MulliganRule::Partial,
+            max_hand_size: 7,
+        }
+    }
+}
+
+impl MatchRules {
+    // Slots in a player's whole play area
+    pub fn board_size(&self) -> usize {
+        self.board_rows * self.board_slots
+    }
+
+    // The lane a slot is in; creatures fight the creatures in the same lane across the board
+    pub fn lane(&self, slot: usize) -> usize {
+        slot % self.board_slots.max(1)
+    }
+
+    // The slots of a lane, front row first
+    pub fn lane_slots(&self, lane: usize) -> impl Iterator<Item = usize> + use<> {
+        let lanes = self.board_slots;
+        (0..self.board_rows).map(move |row| row * lanes + lane)
+    }
+}
+
+// A creature on the board
+#[derive(Debug, Clone)]
+pub struct Unit {
+    pub card: CardInstance,
+    pub attack: u32,
+    pub health: i32,
+    pub max_health: i32,
+    // Creatures can't attack on the turn they are played, unless they have Haste
+    pub can_attack: bool,
+    // Indexes of the activated abilities used this turn
+    pub used_abilities: Vec<usize>,
+}
+
+impl Unit {
+    // A freshly played creature; spells have no stats and never become units
+    pub fn new(card: CardInstance) -> Option<Self> {
+        let stats = card.data.stats?;
+        let health = i32::try_from(stats.health).unwrap_or(i32::MAX);
+        let mut unit = Self {
+            attack: stats.attack,
+            health,
+            max_health: health,
+            can_attack: false,
+            used_abilities: Vec::new(),
+            card,
+        };
+        unit.can_attack = unit.ready_on_play();
+        Some(unit)
+    }
+
+    pub fn is_destroyed(&self) -> bool {
+        self.health <= 0
+    }
+
+    // Rough worth of a unit on the board, used by AI heuristics. Wide enough that stats at
+    // their limits can be added up without overflowing.
+    pub fn value(&self) -> i64 {
+        i64::from(self.attack) + i64::from(self.health) + self.card.data.keywords.len() as i64
+    }
+}
+
+// Everything one player owns
+#[derive(Debug, Clone, Default)]
+pub struct PlayerState {
+    // Top of the deck is the end of the list
+    pub deck: Vec<CardInstance>,
+    pub hand: Vec<CardInstance>,
+    // One entry per play area slot
+    pub board: Vec<Option<Unit>>,
+    // Face-up piles, most recent card last
+    pub graveyard: Vec<CardInstance>,
+    pub exile: Vec<CardInstance>,
+    pub mana: ManaPool,
+    pub life: i32,
+    // Whether the opening hand has been kept or redrawn
+    pub mulligan_done: bool,
+}
+
+impl PlayerState {
+    fn new(deck: Vec<CardInstance>, board_slots: usize) -> Self {
+        Self {
+            deck,
+            board: vec![None; board_slots],
+            life: STARTING_LIFE,
+            ..Default::default()
+        }
+    }
+
+    pub fn pile(&self, kind: PileKind) -> &[CardInstance] {
+        match kind {
+            PileKind::Graveyard => &self.graveyard,
+            PileKind::Exile => &self.exile,
+        }
+    }
+
+    pub fn pile_mut(&mut self, kind: PileKind) -> &mut Vec<CardInstance> {
+        match kind {
+            PileKind::Graveyard => &mut self.graveyard,
+            PileKind::Exile => &mut self.exile,
+        }
+    }
+}
+
+// Why a match ended
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MatchEndReason {
+    LifeDepleted,
+    DeckedOut,
+}
+
+// How a finished match ended; no winner means a draw
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Outcome {
+    pub winner: Option<PlayerSide>,
+    pub reason: MatchEndReason,
+}
+
+// Something the player with priority can do: in their main phase, or in response to
+// something on the stack
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Action {
+    // Play the card at `hand_index`: creatures into the empty slot `slot`, spells anywhere.
+    // `target` is the pick for cards that need one. Only spells can be played in response.
+    Play {
+        hand_index: usize,
+        slot: usize,
+        target: Option<TargetChoice>,
+    },
+    // Use the activated ability at `ability` (an index into the card's abilities) of the
+    // creature in `slot`
+    Activate {
+        slot: usize,
+        ability: usize,
+        target: Option<TargetChoice>,
+    },
+    // End the main phase; the creatures in `slots` attack and the turn passes
+    Attack { slots: Vec<usize> },
+    // Let the top of the stack resolve, unless the other player responds
+    Pass,
+}
+
+// Why an action was refused
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum PlayError {
+    #[error("the match is over")]
+    MatchOver,
+    #[error("there is no card at hand index {0}")]
+    NotInHand(usize),
+    #[error("slot {0} is not an empty play area slot")]
+    SlotUnavailable(usize),
+    #[error("the card costs {cost} mana but only {available} is available")]
+    NotEnoughMana { cost: u32, available: u32 },
+    #[error("slot {0} holds none of the active player's creatures")]
+    NoCreature(usize),
+    #[error("the creature has no activated ability at index {0}")]
+    NoSuchAbility(usize),
+    #[error("the ability was already used this turn")]
+    AbilityUsed,
+    #[error("the creature must be ready to use this ability")]
+    NotReady,
+    #[error("a target has to be picked")]
+    TargetRequired,
+    #[error("that is not a legal target")]
+    IllegalTarget,
+    #[error("the stack has to resolve first")]
+    StackNotEmpty,
+    #[error("there is nothing on the stack to pass on")]
+    StackEmpty,
+    #[error("opening hands can only be redrawn once, before the first turn")]
+    MulliganOver,
+    #[error("the whole hand has to be redrawn, or none of it")]
+    WholeHandOnly,
+}
+
+// A whole match. Turns are driven one step at a time (`start_turn`, `draw_step`, `play`,
+// `move_unit`, `resolve_combat`, `end_turn`, `discard`, `pass_turn`) so a front end can show
+// each phase; `apply` runs a whole action, which is all a search needs. Spells and abilities
+// wait on the stack until both players pass (`pass`), and a phase only moves on once the stack
+// is empty.
+#[derive(Debug, Clone)]
+pub struct MatchState {
+    pub player: PlayerState,
+    pub opponent: PlayerState,
+    pub active: PlayerSide,
+    // Turns started so far; 0 until the first turn begins
+    pub turn_number: u32,
+    pub rules: MatchRules,
+    pub outcome: Option<Outcome>,
+    // Script effects that failed, oldest first, for a front end to show and clear
+    pub script_errors: Vec<ScriptError>,
+    // Spells and abilities waiting to resolve; the last one resolves first
+    pub stack: Vec<StackItem>,
+    // Who acts next: the active player while the stack is empty, otherwise whoever may
+    // respond to the top of the stack
+    pub priority: PlayerSide,
+    // Passes in a row since the stack last changed
+    pub(crate) passes: u8,
+}
+
+impl MatchState {
+    // A new match between two decks (top card last). The player goes first.
+    pub fn new(player_deck: Vec<CardData>, opponent_deck: Vec<CardData>, rules: MatchRules) -> Self {
+        let mut next_id = 0;
+        let mut instances = |deck: Vec<CardData>| -> Vec<CardInstance> {
+            deck.into_iter()
+                .map(|data| {
+                    next_id += 1;
+                    CardInstance {
+                        id: CardId(next_id),
+                        data: Arc::new(data),
+                    }
+                })
+                .collect()
+        };
+        let player_deck = instances(player_deck);
+        let opponent_deck = instances(opponent_deck);
+
+        Self {
+            player: PlayerState::new(player_deck, rules.board_size()),
+            opponent: PlayerState::new(opponent_deck, rules.board_size()),
+            active: PlayerSide::Player,
+            turn_number: 0,
+            rules,
+            outcome: None,
+            script_errors: Vec::new(),
+            stack: Vec::new(),
+            priority: PlayerSide::Player,
+            passes: 0,
+        }
+    }
+
+    pub fn side(&self, side: PlayerSide) -> &PlayerState {
+        match side {
+            PlayerSide::Player => &self.player,
+            PlayerSide::Opponent => &self.opponent,
+        }
+    }
+
+    pub fn side_mut(&mut self, side: PlayerSide) -> &mut PlayerState {
+        match side {
+            PlayerSide::Player => &mut self.player,
+            PlayerSide::Opponent => &mut self.opponent,
+        }
+    }
+
+    pub fn is_over(&self) -> bool {
+        self.outcome.is_some()
+    }
+
+    // Begin the active player's turn: their creatures get ready, their mana refills and
+    // start-of-turn triggers go on the stack
+    pub fn start_turn(&mut self) {
+        self.turn_number += 1;
+
+        let active_side = self.active;
+        let active = self.side_mut(active_side);
+        for unit in active.board.iter_mut().flatten() {
+            unit.can_attack = true;
+            unit.used_abilities.clear();
+        }
+        active.mana.refill();
+        self.fire_board_trigger(active_side, Trigger::StartOfTurn);
+    }
+
+    // Put end-of-turn triggers on the stack; once it is empty, `pass_turn` hands the turn over
+    pub fn end_turn(&mut self) {
+        self.fire_board_trigger(self.active, Trigger::EndOfTurn);
+    }
+
+    // Hand the turn to the other player, once they are down to the maximum hand size (see
+    // `hand_overflow` and `discard`)
+    pub fn pass_turn(&mut self) {
+        self.active = self.active.other();
+        self.priority = self.active;
+    }
+
+    // With the deck-out rule on, an active player who has to draw from an empty deck loses.
+    // Returns whether that ended the match.
+    pub fn check_deck_out(&mut self) -> bool {
+        if self.is_over() || !self.rules.deck_out_loses || !self.side(self.active).deck.is_empty() {
+            return false;
+        }
+
+        self.outcome = Some(Outcome {
+            winner: Some(self.active.other()),
+            reason: MatchEndReason::DeckedOut,
+        });
+        true
+    }
+
+    // Move the top card of a deck into its owner's hand
+    pub fn draw(&mut self, side: PlayerSide) -> Option<CardId> {
+        let player = self.side_mut(side);
+        let card = player.deck.pop()?;
+        let id = card.id;
+        player.hand.push(card);
+        Some(id)
+    }
+
+    // The active player's draw phase: a deck-out check, then one card
+    pub fn draw_step(&mut self) {
+        if !self.check_deck_out() {
+            self.draw(self.active);
+        }
+    }
+
+    // How many cards `side` holds over the maximum hand size
+    pub fn hand_overflow(&self, side: PlayerSide) -> usize {
+        self.side(side).hand.len().saturating_sub(self.rules.max_hand_size)
+    }
+
+    // `side` puts the card at `hand_index` into their graveyard
+    pub fn discard(&mut self, side: PlayerSide, hand_index: usize) -> Result<(), PlayError> {
+        let player = self.side_mut(side);
+        if hand_index >= player.hand.len() {
+            return Err(PlayError::NotInHand(hand_index));
+        }
+        let card = player.hand.remove(hand_index);
+        player.graveyard.push(card);
+        Ok(())
+    }
+
+    // Affordable cards for the player with priority, creatures into each empty slot, with
+    // every legal target. Spells and duplicate cards in hand would only repeat the same
+    // outcome, so each distinct card is offered once. In response, only spells.
+    pub fn legal_plays(&self) -> Vec<Action> {
+        let active = self.side(self.priority);
+        let mut plays = Vec::new();
+        if self.is_over() {
+            return plays;
+        }
+
+        let empty_slots: Vec<usize> = active
+            .board
+            .iter()
+            .enumerate()
+            .filter(|(_, unit)| unit.is_none())
+            .map(|(slot, _)| slot)
+            .collect();
+        for (hand_index, card) in active.hand.iter().enumerate() {
+            if !active.mana.can_pay(card.data.cost)
+                || active.hand[..hand_index].iter().any(|earlier| earlier.data.id == card.data.id)
+            {
+                continue;
+            }
+            let is_creature = card.data.stats.is_some();
+            if is_creature && !self.stack.is_empty() {
+                continue;
+            }
+            let slots = if is_creature { empty_slots.as_slice() } else { &[0] };
+            let targets = self.target_options(card.data.play_target(), is_creature);
+            for &slot in slots {
+                for &target in &targets {
+                    plays.push(Action::Play { hand_index, slot, target });
+                }
+            }
+        }
+        plays
+    }
+
+    // Activated abilities the player with priority can use and pay for right now, with every
+    // legal target
+    pub fn legal_activations(&self) -> Vec<Action> {
+        let mut activations = Vec::new();
+        for (slot, unit) in self.side(self.priority).board.iter().enumerate() {
+            let Some(unit) = unit else {
+                continue;
+            };
+            for (ability, entry) in unit.card.data.abilities.iter().enumerate() {
+                if self.check_activation(slot, ability).is_err() {
+                    continue;
+                }
+                for target in self.target_options(entry.chosen_target(), false) {
+                    activations.push(Action::Activate { slot, ability, target });
+                }
+            }
+        }
+        activations
+    }
+
+    // Slots of the active player's creatures that can attack this turn
+    pub fn ready_attackers(&self) -> Vec<usize> {
+        self.side(self.active)
+            .board
+            .iter()
+            .enumerate()
+            .filter(|(_, unit)| unit.as_ref().is_some_and(|unit| unit.can_attack && unit.attack > 0))
+            .map(|(slot, _)| slot)
+            .collect()
+    }
+
+    // Every way to end the main phase: each combination of ready attackers
+    pub fn legal_attacks(&self) -> Vec<Action> {
+        if self.is_over() || !self.stack.is_empty() {
+            return Vec::new();
+        }
+
+        let ready = self.ready_attackers();
+        let subsets: Vec<Vec<usize>> = if ready.len() <= MAX_ATTACKERS_FOR_ALL_SUBSETS {
+            (0..1usize << ready.len())
+                .map(|mask| {
+                    ready
+                        .iter()
+                        .enumerate()
+                        .filter(|(bit, _)| mask & (1 << bit) != 0)
+                        .map(|(_, &slot)| slot)
+                        .collect()
+                })
+                .collect()
+        } else {
+            // Everything, nothing, each creature alone and all but each creature
+            let mut subsets = vec![ready.clone(), Vec::new()];
+            for &slot in &ready {
+                subsets.push(vec![slot]);
+                subsets.push(ready.iter().copied().filter(|&other| other != slot).collect());
+            }
+            subsets
+        };
+
+        subsets.into_iter().map(|slots| Action::Attack { slots }).collect()
+    }
+
+    // Everything the player with priority can do: their plays and abilities, and then either
+    // an attack or, with something on the stack, a pass
+    pub fn legal_actions(&self) -> Vec<Action> {
+        let mut actions = self.legal_plays();
+        actions.extend(self.legal_activations());
+        if self.stack.is_empty() {
+            actions.extend(self.legal_attacks());
+        } else if !self.is_over() {
+            actions.push(Action::Pass);
+        }
+        actions
+    }
+
+    // Apply an action for the player with priority. Attacking also finishes the turn and runs
+    // the next player's start and draw; what triggers along the way resolves unanswered.
+    pub fn apply(&mut self, action: &Action) -> Result<(), PlayError> {
+        match *action {
+            Action::Play { hand_index, slot, target } => self.play(hand_index, slot, target),
+            Action::Activate { slot, ability, target } => self.activate(slot, ability, target),
+            Action::Pass => self.pass(),
+            Action::Attack { ref slots } => {
+                if self.is_over() {
+                    return Err(PlayError::MatchOver);
+                }
+                if !self.stack.is_empty() {
+                    return Err(PlayError::StackNotEmpty);
+                }
+                self.resolve_combat(slots);
+                self.resolve_stack();
+                if !self.is_over() {
+                    self.end_turn();
+                    self.resolve_stack();
+                }
+                if !self.is_over() {
+                    // A search doesn't weigh which cards to keep: the newest ones go
+                    while self.hand_overflow(self.active) > 0 {
+                        let newest = self.side(self.active).hand.len() - 1;
+                        self.discard(self.active, newest)?;
+                    }
+                    self.pass_turn();
+                    self.start_turn();
+                    self.resolve_stack();
+                    self.draw_step();
+                }
+                Ok(())
+            }
+        }
+    }
+
+    // Whether the player with priority can pay for the card at `hand_index` and put it in
+    // `slot`, leaving its target aside. Spells don't need a slot; creatures need an empty
+    // stack.
+    pub fn check_play(&self, hand_index: usize, slot: usize) -> Result<(), PlayError> {
+        if self.is_over() {
+            return Err(PlayError::MatchOver);
+        }
+
+        let active = self.side(self.priority);
+        let Some(card) = active.hand.get(hand_index) else {
+            return Err(PlayError::NotInHand(hand_index));
+        };
+        if card.data.stats.is_some() && !self.stack.is_empty() {
+            return Err(PlayError::StackNotEmpty);
+        }
+        if card.data.stats.is_some() && !matches!(active.board.get(slot), Some(None)) {
+            return Err(PlayError::SlotUnavailable(slot));
+        }
+        if !active.mana.can_pay(card.data.cost) {
+            return Err(PlayError::NotEnoughMana {
+                cost: card.data.cost,
+                available: active.mana.current,
+            });
+        }
+        Ok(())
+    }
+
+    // The player with priority pays for the card at `hand_index` and plays it: creatures take
+    // the empty slot `slot` and put their on-play triggers on the stack; spells go on the
+    // stack themselves, and to the graveyard once they resolve. `target` is the pick for
+    // whichever needs one. Nothing changes on an error.
+    pub fn play(&mut self, hand_index: usize, slot: usize, target: Option<TargetChoice>) -> Result<(), PlayError> {
+        self.check_play(hand_index, slot)?;
+        let acting_side = self.priority;
+        let card = self.side(acting_side).hand[hand_index].clone();
+        let is_creature = card.data.stats.is_some();
+        self.check_target(card.data.play_target(), target, is_creature)?;
+
+        let acting = self.side_mut(acting_side);
+        acting.mana.spend(card.data.cost);
+        acting.hand.remove(hand_index);
+        match Unit::new(card.clone()) {
+            Some(unit) => {
+                acting.board[slot] = Some(unit);
+                self.push_triggers(&card, acting_side, Some(slot), target, Trigger::OnPlay);
+            }
+            None => {
+                let effects = card.data.triggered(Trigger::OnPlay).flatten().cloned().collect();
+                self.push(StackItem {
+                    card,
+                    kind: StackKind::Spell,
+                    controller: acting_side,
+                    target,
+                    slot: None,
+                    effects,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    // Whether the player with priority can use and pay for an activated ability of their
+    // creature in `slot`, leaving its target aside
+    pub fn check_activation(&self, slot: usize, ability: usize) -> Result<(), PlayError> {
+        if self.is_over() {
+            return Err(PlayError::MatchOver);
+        }
+
+        let active = self.side(self.priority);
+        let Some(Some(unit)) = active.board.get(slot) else {
+            return Err(PlayError::NoCreature(slot));
+        };
+        let Some(Ability::Activated { mana, exhaust, .. }) = unit.card.data.abilities.get(ability) else {
+            return Err(PlayError::NoSuchAbility(ability));
+        };
+        if unit.used_abilities.contains(&ability) {
+            return Err(PlayError::AbilityUsed);
+        }
+        if *exhaust && !unit.can_attack {
+            return Err(PlayError::NotReady);
+        }
+        if !active.mana.can_pay(*mana) {
+            return Err(PlayError::NotEnoughMana {
+                cost: *mana,
+                available: active.mana.current,
+            });
+        }
+        Ok(())
+    }
+
+    // The player with priority uses an activated ability of their creature in `slot`, paying
+    // its cost, and puts it on the stack against `target` if it needs one. Nothing changes on
+    // an error.
+    pub fn activate(&mut self, slot: usize, ability: usize, target: Option<TargetChoice>) -> Result<(), PlayError> {
+        self.check_activation(slot, ability)?;
+        let acting_side = self.priority;
+        let Some(Some(unit)) = self.side(acting_side).board.get(slot) else {
+            return Err(PlayError::NoCreature(slot));
+        };
+        let card = unit.card.clone();
+        let Some(entry @ Ability::Activated { mana, exhaust, effects }) = card.data.abilities.get(ability) else {
+            return Err(PlayError::NoSuchAbility(ability));
+        };
+        self.check_target(entry.chosen_target(), target, false)?;
+
+        let acting = self.side_mut(acting_side);
+        acting.mana.spend(*mana);
+        if let Some(Some(unit)) = acting.board.get_mut(slot) {
+            unit.used_abilities.push(ability);
+            if *exhaust {
+                unit.can_attack = false;
+            }
+        }
+
+        self.push(StackItem {
+            kind: StackKind::Activated,
+            controller: acting_side,
+            target,
+            slot: Some(slot),
+            effects: effects.clone(),
+            card,
+        });
+        Ok(())
+    }
+
+    // Whether the active player can move their creature in `from` to the empty slot `to`.
+    // Only a ready creature can move, and only while the stack is empty.
+    pub fn check_move(&self, from: usize, to: usize) -> Result<(), PlayError> {
+        if self.is_over() {
+            return Err(PlayError::MatchOver);
+        }
+        if !self.stack.is_empty() {
+            return Err(PlayError::StackNotEmpty);
+        }
+
+        let active = self.side(self.active);
+        let Some(Some(unit)) = active.board.get(from) else {
+            return Err(PlayError::NoCreature(from));
+        };
+        if active.board.get(to).is_none_or(Option::is_some) {
+            return Err(PlayError::SlotUnavailable(to));
+        }
+        if !unit.can_attack {
+            return Err(PlayError::NotReady);
+        }
+        Ok(())
+    }
+
+    // The active player moves their creature in `from` to the empty slot `to`. Moving takes
+    // the creature's attack for the turn. Nothing changes on an error.
+    pub fn move_unit(&mut self, from: usize, to: usize) -> Result<(), PlayError> {
+        self.check_move(from, to)?;
+        let board = &mut self.side_mut(self.active).board;
+        let mut unit = board[from].take().ok_or(PlayError::NoCreature(from))?;
+        unit.can_attack = false;
+        board[to] = Some(unit);
+        Ok(())
+    }
+
+    // The creatures in `slots` attack down their lane. If a creature blocks (see
+    // `blocker_for`) the two deal damage to each other; otherwise the defending player takes it.
+    // Creatures that can't attack yet are skipped, and destroyed ones go to their owner's
+    // graveyard (putting their death triggers on the stack).
+    pub fn resolve_combat(&mut self, slots: &[usize]) {
+        let attacker_side = self.active;
+        let defender_side = attacker_side.other();
+
+        for &slot in slots {
+            let Some(attacker) = self.side(attacker_side).board.get(slot).cloned().flatten() else {
+                continue;
+            };
+            if !attacker.can_attack || attacker.attack == 0 {
+                continue;
+            }
+
+            let blocker_slot = self.blocker_for(defender_side, slot, &attacker);
+            match blocker_slot.and_then(|blocker_slot| self.side(defender_side).board[blocker_slot].clone()) {
+                Some(blocker) => {
+                    if let Some(Some(unit)) = blocker_slot.map(|blocker_slot| &mut self.side_mut(defender_side).board[blocker_slot]) {
+                        attacker.strike(unit);
+                    }
+                    if let Some(Some(unit)) = self.side_mut(attacker_side).board.get_mut(slot) {
+                        blocker.strike(unit);
+                    }
+                    self.lifesteal(attacker_side, &attacker, attacker.attack);
+                    self.lifesteal(defender_side, &blocker, blocker.attack);
+                }
+                None => {
+                    let defender = self.side_mut(defender_side);
+                    defender.life = defender.life.saturating_sub(i32::try_from(attacker.attack).unwrap_or(i32::MAX));
+                    self.lifesteal(attacker_side, &attacker, attacker.attack);
+                }
+            }
+        }
+
+        self.check_life_totals();
+        self.remove_destroyed();
+        self.check_life_totals();
+    }
+
+    // End the match when a player's life reaches zero (both at once is a draw)
+    pub(crate) fn check_life_totals(&mut self) {
+        if self.is_over() {
+            return;
+        }
+
+        let player_dead = self.player.life <= 0;
+        let opponent_dead = self.opponent.life <= 0;
+        let winner = match (player_dead, opponent_dead) {
+            (false, false) => return,
+            (true, true) => None,
+            (true, false) => Some(PlayerSide::Opponent),
+            (false, true) => Some(PlayerSide::Player),
+        };
+        self.outcome = Some(Outcome {
+            winner,
+            reason: MatchEndReason::LifeDepleted,
+        });
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::card::CardStats;
+    use crate::effect::{Effect, Target};
+    use crate::keyword::Keyword;
+    use crate::mana::ManaPool;
+
+    // A creature card with no abilities
+    pub(crate) fn creature(id: &str, cost: u32, attack: u32, health: u32, keywords: &[Keyword]) -> CardData {
+        CardData {
+            id: id.to_string(),
+            name: id.to_string(),
+            cost,
+            stats: Some(CardStats { attack, health }),
+            rules_text: String::new(),
+            art: None,
+            tags: Vec::new(),
+            keywords: keywords.to_vec(),
+            abilities: Vec::new(),
+        }
+    }
+
+    // A spell card that resolves `effects` when played
+    pub(crate) fn spell(id: &str, cost: u32, effects: Vec<Effect>) -> CardData {
+        CardData {
+            id: id.to_string(),
+            name: id.to_string(),
+            cost,
+            stats: None,
+            rules_text: String::new(),
+            art: None,
+            tags: Vec::new(),
+            keywords: Vec::new(),
+            abilities: vec![Ability::Triggered { trigger: Trigger::OnPlay, effects }],
+        }
+    }
+
+    // An empty match in the player's first main phase, with `mana` to spend and `hand` in hand
+    pub(crate) fn match_with_hand(hand: Vec<CardData>, mana: u32) -> MatchState {
+        // The top of a deck is its end, so reversed it draws into a hand in list order
+        let mut state = MatchState::new(hand.into_iter().rev().collect(), Vec::new(), MatchRules::default());
+        while state.draw(PlayerSide::Player).is_some() {}
+        state.turn_number = 1;
+        state.player.mana = ManaPool { current: mana, max: mana };
+        state
+    }
+
+    // Put a creature straight onto `side`'s board, ready to attack
+    pub(crate) fn place(state: &mut MatchState, side: PlayerSide, slot: usize, card: CardData) {
+        // Ids well clear of the decks' own
+        let first_id = match side {
+            PlayerSide::Player => 1000,
+            PlayerSide::Opponent => 2000,
+        };
+        let id = CardId(first_id + slot as u32);
+        let mut unit = Unit::new(CardInstance { id, data: Arc::new(card) }).unwrap();
+        unit.can_attack = true;
+        state.side_mut(side).board[slot] = Some(unit);
+    }
+
+    #[test]
+    fn legal_plays_only_offer_affordable_cards() {
+        let state = match_with_hand(vec![creature("cheap", 2, 1, 1, &[]), creature("dear", 5, 4, 4, &[])], 3);
+
+        let plays = state.legal_plays();
+        assert_eq!(plays.len(), state.rules.board_size());
+        assert!(plays.iter().all(|play| matches!(play, Action::Play { hand_index: 0, .. })));
+
+        // Nothing can attack yet, so the only way to end the turn is without attackers
+        let attacks: Vec<_> = state.legal_actions().into_iter().filter(|action| matches!(action, Action::Attack { .. })).collect();
+        assert_eq!(attacks, vec![Action::Attack { slots: Vec::new() }]);
+    }
+
+    #[test]
+    fn playing_a_creature_pays_for_it_and_fills_the_slot() {
+        let mut state = match_with_hand(vec![creature("bear", 2, 2, 2, &[])], 3);
+
+        state.apply(&Action::Play { hand_index: 0, slot: 2, target: None }).unwrap();
+
+        assert!(state.player.hand.is_empty());
+        assert_eq!(state.player.mana.current, 1);
+        let unit = state.player.board[2].as_ref().unwrap();
+        assert_eq!((unit.attack, unit.health), (2, 2));
+        assert!(!unit.can_attack);
+    }
+
+    #[test]
+    fn illegal_plays_are_rejected_without_changing_anything() {
+        let mut state = match_with_hand(vec![creature("bear", 2, 2, 2, &[]), creature("giant", 5, 5, 5, &[])], 3);
+        place(&mut state, PlayerSide::Player, 0, creature("wall", 1, 0, 4, &[]));
+
+        assert_eq!(state.check_play(1, 1), Err(PlayError::NotEnoughMana { cost: 5, available: 3 }));
+        assert_eq!(state.check_play(7, 1), Err(PlayError::NotInHand(7)));
+        assert_eq!(state.check_play(0, 0), Err(PlayError::SlotUnavailable(0)));
+        assert_eq!(state.check_play(0, 99), Err(PlayError::SlotUnavailable(99)));
+        assert_eq!(
+            state.apply(&Action::Play { hand_index: 1, slot: 1, target: None }),
+            Err(PlayError::NotEnoughMana { cost: 5, available: 3 })
+        );
+        assert_eq!(state.apply(&Action::Pass), Err(PlayError::StackEmpty));
+        assert_eq!(state.player.hand.len(), 2);
+        assert_eq!(state.player.mana.current, 3);
+
+        state.outcome = Some(Outcome { winner: None, reason: MatchEndReason::LifeDepleted });
+        assert_eq!(state.check_play(0, 1), Err(PlayError::MatchOver));
+        assert_eq!(state.apply(&Action::Attack { slots: Vec::new() }), Err(PlayError::MatchOver));
+        assert!(state.legal_actions().is_empty());
+    }
+
+    #[test]
+    fn creatures_and_attacks_wait_for_the_stack() {
+        let bolt = spell("bolt", 1, vec![Effect::Damage { amount: 1, target: Target::Opponent }]);
+        let mut state = match_with_hand(vec![bolt], 3);
+        state.opponent.hand.push(CardInstance { id: CardId(99), data: Arc::new(creature("bear", 0, 2, 2, &[])) });
+
+        state.play(0, 0, None).unwrap();
+
+        // The opponent may respond, but only with spells and abilities
+        assert_eq!(state.stack.len(), 1);
+        assert_eq!(state.priority, PlayerSide::Opponent);
+        assert_eq!(state.check_play(0, 0), Err(PlayError::StackNotEmpty));
+        assert_eq!(state.legal_actions(), vec![Action::Pass]);
+        assert_eq!(state.apply(&Action::Attack { slots: Vec::new() }), Err(PlayError::StackNotEmpty));
+    }
+
+    #[test]
+    fn an_attack_into_an_empty_lane_hits_the_player() {
+        let mut state = match_with_hand(Vec::new(), 0);
+        place(&mut state, PlayerSide::Player, 1, creature("bear", 2, 3, 2, &[]));
+        place(&mut state, PlayerSide::Opponent, 0, creature("wall", 1, 0, 4, &[]));
+
+        state.resolve_combat(&[1]);
+
+        assert_eq!(state.opponent.life, STARTING_LIFE - 3);
+        assert_eq!(state.opponent.board[0].as_ref().unwrap().health, 4);
+    }
+
+    #[test]
+    fn an_attack_into_an_occupied_lane_fights_the_blocker() {
+        let mut state = match_with_hand(Vec::new(), 0);
+        place(&mut state, PlayerSide::Player, 0, creature("bear", 2, 3, 3, &[]));
+        place(&mut state, PlayerSide::Opponent, 0, creature("wolf", 2, 2, 3, &[]));
+
+        state.resolve_combat(&[0]);
+
+        assert_eq!(state.opponent.life, STARTING_LIFE);
+        assert_eq!(state.player.board[0].as_ref().unwrap().health, 1);
+        // The blocker died and went to its owner's graveyard
+        assert!(state.opponent.board[0].is_none());
+        assert_eq!(state.opponent.graveyard.len(), 1);
+        assert_eq!(state.opponent.graveyard[0].data.id, "wolf");
+    }
+
+    #[test]
+    fn creatures_that_are_not_ready_do_not_attack() {
+        let mut state = match_with_hand(Vec::new(), 0);
+        place(&mut state, PlayerSide::Player, 0, creature("bear", 2, 3, 3, &[]));
+        state.player.board[0].as_mut().unwrap().can_attack = false;
+
+        assert!(state.ready_attackers().is_empty());
+        state.resolve_combat(&[0, 3]);
+        assert_eq!(state.opponent.life, STARTING_LIFE);
+    }
+
+    #[test]
+    fn destroyed_creatures_leave_the_board_for_the_graveyard() {
+        let sweep = spell("sweep", 2, vec![Effect::Damage { amount: 2, target: Target::AllCreatures }]);
+        let mut state = match_with_hand(vec![sweep], 2);
+        place(&mut state, PlayerSide::Player, 0, creature("bear", 2, 2, 2, &[]));
+        place(&mut state, PlayerSide::Opponent, 1, creature("wolf", 2, 2, 1, &[]));
+        place(&mut state, PlayerSide::Opponent, 2, creature("oak", 4, 1, 5, &[]));
+
+        state.play(0, 0, None).unwrap();
+        state.resolve_stack();
+
+        assert!(state.player.board[0].is_none());
+        assert!(state.opponent.board[1].is_none());
+        assert_eq!(state.opponent.board[2].as_ref().unwrap().health, 3);
+        let graveyard = |side: &PlayerState| side.graveyard.iter().map(|card| card.data.id.clone()).collect::<Vec<_>>();
+        assert_eq!(graveyard(&state.player), vec!["bear", "sweep"]);
+        assert_eq!(graveyard(&state.opponent), vec!["wolf"]);
+    }
+
+    #[test]
+    fn drawing_from_an_empty_deck_loses_with_the_deck_out_rule() {
+        let mut state = match_with_hand(Vec::new(), 0);
+
+        state.draw_step();
+
+        assert_eq!(
+            state.outcome,
+            Some(Outcome { winner: Some(PlayerSide::Opponent), reason: MatchEndReason::DeckedOut })
+        );
+    }
+
+    #[test]
+    fn drawing_from_an_empty_deck_is_harmless_without_the_deck_out_rule() {
+        let mut state = match_with_hand(Vec::new(), 0);
+        state.rules.deck_out_loses = false;
+
+        state.draw_step();
+
+        assert_eq!(state.outcome, None);
+        assert!(state.player.hand.is_empty());
+    }
+
+    #[test]
+    fn a_player_at_zero_life_loses() {
+        let mut state = match_with_hand(Vec::new(), 0);
+        place(&mut state, PlayerSide::Player, 0, creature("bear", 2, 3, 3, &[]));
+        state.opponent.life = 3;
+
+        state.resolve_combat(&[0]);
+
+        assert_eq!(
+            state.outcome,
+            Some(Outcome { winner: Some(PlayerSide::Player), reason: MatchEndReason::LifeDepleted })
+        );
+        assert!(state.legal_actions().is_empty());
+    }
+
+    #[test]
+    fn both_players_at_zero_life_is_a_draw() {
+        let mut state = match_with_hand(Vec::new(), 0);
+        state.player.life = 0;
+        state.opponent.life = -2;
+
+        state.check_life_totals();
+
+        assert_eq!(state.outcome, Some(Outcome { winner: None, reason: MatchEndReason::LifeDepleted }));
+    }
+
+    #[test]
+    fn unit_value_fits_stats_at_their_limits() {
+        let mut state = match_with_hand(Vec::new(), 0);
+        place(&mut state, PlayerSide::Player, 0, creature("giant", 0, u32::MAX, i32::MAX as u32, &[Keyword::Guard]));
+
+        let value = state.player.board[0].as_ref().unwrap().value();
+        assert_eq!(value, i64::from(u32::MAX) + i64::from(i32::MAX) + 1);
+    }
+
+    #[test]
+    fn huge_amounts_saturate_instead_of_wrapping() {
+        let nuke = spell("nuke", 0, vec![Effect::Damage { amount: u32::MAX, target: Target::Opponent }]);
+        let mut state = match_with_hand(vec![nuke], 0);
+        place(&mut state, PlayerSide::Player, 0, creature("giant", 0, u32::MAX, 1, &[]));
+
+        state.resolve_combat(&[0]);
+        assert_eq!(state.opponent.life, STARTING_LIFE - i32::MAX);
+
+        state.outcome = None;
+        state.play(0, 0, None).unwrap();
+        state.resolve_stack();
+        assert_eq!(state.opponent.life, i32::MIN);
+        assert_eq!(state.outcome.map(|outcome| outcome.winner), Some(Some(PlayerSide::Player)));
+    }
+}
@@ -0,0 +1,101 @@
+use bevy::prelude::*;
+use cardigan_core::{Ability, PlayError, PlayerSide};
+use crate::GameState;
+use crate::combat::zone_slot;
+use crate::gameplay::{card_drag_system, sync_cards_system, Card, CardZone, Dragging, GameplayState};
+use crate::mana::RejectedPlay;
+use crate::picking::topmost_at;
+use crate::piles::pile_browser_closed;
+use crate::startup::VirtualResolution;
+use crate::targeting::{start_targeting, PendingAction, Targeting};
+use crate::turn::player_can_act;
+
+// Plugin initializer for activated abilities
+pub fn init_ability_systems(app: &mut App) {
+    app.add_systems(
+        Update,
+        activate_ability_system
+            // Sees a drag before it is dropped, to tell a click from a move
+            .before(card_drag_system)
+            .before(sync_cards_system)
+            .run_if(
+                in_state(GameState::Playing)
+                    .and(player_can_act)
+                    .and(pile_browser_closed)
+                    .and(not(resource_exists::<Targeting>)),
+            ),
+    );
+}
+
+// Clicking one of the player's creatures uses its first activated ability that hasn't been
+// used this turn (in their main phase or in response), first asking for a target if the
+// ability needs one. The click counts on release, so dragging the creature to another slot
+// doesn't use it. Triggered abilities happen by themselves inside the rules.
+pub fn activate_ability_system(
+    mut commands: Commands,
+    card_query: Query<(Entity, &Card, &CardZone, &Transform, &Sprite, Option<&Dragging>)>,
+    mut gameplay_state: ResMut<GameplayState>,
+    mouse_button: Res<ButtonInput<MouseButton>>,
+    window_query: Query<&Window>,
+    resolution: Res<VirtualResolution>,
+) {
+    if !mouse_button.just_released(MouseButton::Left) {
+        return;
+    }
+
+    let Some(window) = window_query.iter().next() else {
+        return;
+    };
+
+    let Some(cursor_world_pos) = resolution.cursor_position(window) else {
+        return;
+    };
+
+    // The player's topmost board card under the cursor
+    let board_cards = card_query.iter().filter_map(|(entity, card, zone, transform, sprite, dragging)| {
+        let (PlayerSide::Player, slot) = zone_slot(zone)? else {
+            return None;
+        };
+        let size = sprite.custom_size?;
+        if dragging.is_some_and(|drag| drag.moved(transform.translation.truncate(), size)) {
+            return None;
+        }
+        Some(((entity, card, slot), transform, sprite))
+    });
+    let clicked = topmost_at(cursor_world_pos, board_cards);
+    let Some((entity, card, slot)) = clicked else {
+        return;
+    };
+
+    let Some(Some(unit)) = gameplay_state.player.board.get(slot) else {
+        return;
+    };
+    let Some(ability) = card.data.abilities.iter().enumerate().find_map(|(index, ability)| {
+        let usable = matches!(ability, Ability::Activated { .. }) && !unit.used_abilities.contains(&index);
+        usable.then_some(index)
+    }) else {
+        return;
+    };
+
+    let result = gameplay_state.check_activation(slot, ability).and_then(|()| {
+        match card.data.abilities[ability].chosen_target() {
+            Some(requirement) => {
+                let legal = gameplay_state.legal_targets(PlayerSide::Player, requirement);
+                if legal.is_empty() {
+                    return Err(PlayError::TargetRequired);
+                }
+                start_targeting(&mut commands, PendingAction::Activate { slot, ability }, entity, legal);
+                Ok(())
+            }
+            None => gameplay_state.activate(slot, ability, None),
+        }
+    });
+    match result {
+        Ok(()) => {}
+        // Nothing to use it on, or no mana to pay with: the card flashes to show why
+        Err(PlayError::NotEnoughMana { .. } | PlayError::TargetRequired) => {
+            commands.entity(entity).insert(RejectedPlay::new());
+        }
+        Err(error) => debug!("Can't use {}'s ability: {}", card.data.name, error),
+    }
+}
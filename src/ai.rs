@@ -0,0 +1,318 @@
+use bevy::prelude::*;
+use cardigan_core::{Action, MatchState, MulliganRule, PlayerSide, PlayerState, Unit};
+use rand::{Rng, RngCore};
+use crate::mcts::{MctsAi, MctsSettings};
+
+// Opening hand cards costing more than this are too slow to keep
+const MULLIGAN_MAX_COST: u32 = 3;
+
+// How hard the computer opponent plays; picked in the main menu
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Difficulty {
+    Easy,
+    #[default]
+    Normal,
+    Hard,
+}
+
+impl Difficulty {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Difficulty::Easy => "EASY",
+            Difficulty::Normal => "NORMAL",
+            Difficulty::Hard => "HARD",
+        }
+    }
+
+    // The next difficulty when cycling through them in the menu
+    pub fn next(&self) -> Self {
+        match self {
+            Difficulty::Easy => Difficulty::Normal,
+            Difficulty::Normal => Difficulty::Hard,
+            Difficulty::Hard => Difficulty::Easy,
+        }
+    }
+
+    // A fresh opponent for this difficulty
+    pub fn opponent(&self, mcts_settings: &MctsSettings) -> Box<dyn OpponentAi> {
+        match self {
+            Difficulty::Easy => Box::new(RandomAi),
+            Difficulty::Normal => Box::new(GreedyAi),
+            Difficulty::Hard => Box::new(MctsAi::new(mcts_settings.clone())),
+        }
+    }
+}
+
+// A computer opponent's decision making. It is asked for one action at a time during its
+// main phase, until it picks an attack (which ends the phase), and for a response or a pass
+// whenever it has priority over something on the stack. Decisions run on a worker thread, so
+// implementations must be Send.
+pub trait OpponentAi: Send + Sync {
+    // Choose one of `state.legal_actions()` for the player with priority
+    fn choose_action(&mut self, state: &MatchState, rng: &mut dyn RngCore) -> Action;
+
+    // Which cards of `side`'s opening hand to shuffle back. By default that is every card too
+    // slow for the first turns, or under the full rule the whole hand if all of it is.
+    fn choose_mulligan(&mut self, state: &MatchState, side: PlayerSide) -> Vec<usize> {
+        let hand = &state.side(side).hand;
+        let slow: Vec<usize> = (0..hand.len())
+            .filter(|&index| hand[index].data.cost > MULLIGAN_MAX_COST)
+            .collect();
+        match state.rules.mulligan {
+            MulliganRule::Partial => slow,
+            MulliganRule::Full if slow.len() == hand.len() => slow,
+            MulliganRule::Full => Vec::new(),
+        }
+    }
+
+    // Which card `side` discards when over the maximum hand size. By default that is the most
+    // expensive one, the card least likely to be played soon.
+    fn choose_discard(&mut self, state: &MatchState, side: PlayerSide) -> usize {
+        let hand = &state.side(side).hand;
+        (0..hand.len())
+            .max_by_key(|&index| hand[index].data.cost)
+            .unwrap_or(0)
+    }
+}
+
+// Picks uniformly among its plays, activations and passing; when it passes in its main phase,
+// each ready creature attacks on a coin flip
+pub struct RandomAi;
+
+impl OpponentAi for RandomAi {
+    fn choose_action(&mut self, state: &MatchState, rng: &mut dyn RngCore) -> Action {
+        let mut plays = state.legal_plays();
+        plays.extend(state.legal_activations());
+        // One extra choice for passing
+        let choice = rng.random_range(0..=plays.len());
+        if let Some(play) = plays.get(choice) {
+            return play.clone();
+        }
+        if !state.stack.is_empty() {
+            return Action::Pass;
+        }
+
+        let slots = state
+            .ready_attackers()
+            .into_iter()
+            .filter(|_| rng.random_bool(0.5))
+            .collect();
+        Action::Attack { slots }
+    }
+}
+
+// Plays its biggest affordable creature into the best lane, then any spell or ability that
+// leaves it better off (also in response), and only attacks when the attack is free or trades
+// up
+pub struct GreedyAi;
+
+impl GreedyAi {
+    // How good it is to put `unit` in `slot`, against whatever would block it in that lane
+    fn slot_score(state: &MatchState, unit: &Unit, slot: usize) -> i32 {
+        let enemy_side = state.priority.other();
+        let blocker = state
+            .blocker_for(enemy_side, slot, unit)
+            .and_then(|blocker_slot| state.side(enemy_side).board[blocker_slot].as_ref());
+        match blocker {
+            // An open lane lets it hit the opponent's face
+            None => 2,
+            Some(enemy) => {
+                let kills = unit.kills(enemy);
+                let survives = !enemy.kills(unit);
+                match (kills, survives) {
+                    (true, true) => 4,
+                    (false, true) => 3,
+                    (true, false) => 1,
+                    (false, false) => 0,
+                }
+            }
+        }
+    }
+
+    // The best creature play, if any
+    fn choose_play(state: &MatchState) -> Option<Action> {
+        let own = state.side(state.priority);
+
+        state
+            .legal_plays()
+            .into_iter()
+            .filter_map(|play| {
+                let Action::Play { hand_index, slot, target } = play else {
+                    return None;
+                };
+                let card = &own.hand[hand_index];
+                // Spells are weighed by their effects in `choose_effect`
+                let unit = Unit::new(card.clone())?;
+                let score = (card.data.cost, unit.value(), Self::slot_score(state, &unit, slot));
+                // Between targets for the same play, the one that leaves the best position
+                let target_score = target.and_then(|_| {
+                    let mut after = state.clone();
+                    after.apply(&play).ok()?;
+                    after.resolve_stack();
+                    Some(Self::advantage(&after, state.priority))
+                });
+                Some(((score, std::cmp::Reverse(slot), target_score), play))
+            })
+            .max_by_key(|(score, _)| *score)
+            .map(|(_, play)| play)
+    }
+
+    // How far ahead `side` is: life, board and cards in hand. Counted in i64s, since life and
+    // stats can each be anywhere in their i32 range.
+    fn advantage(state: &MatchState, side: PlayerSide) -> i64 {
+        let score = |player: &PlayerState| {
+            let board: i64 = player.board.iter().flatten().map(Unit::value).sum();
+            i64::from(player.life) + board + player.hand.len() as i64
+        };
+        score(state.side(side)) - score(state.side(side.other()))
+    }
+
+    // The spell or activated ability that improves the position the most, if any does,
+    // compared with letting the stack resolve without it
+    fn choose_effect(state: &MatchState) -> Option<Action> {
+        let me = state.priority;
+        let own = state.side(me);
+        let mut passed = state.clone();
+        passed.resolve_stack();
+        let before = Self::advantage(&passed, me);
+
+        let spells = state.legal_plays().into_iter().filter(|play| {
+            matches!(play, Action::Play { hand_index, .. } if own.hand[*hand_index].data.stats.is_none())
+        });
+        spells
+            .chain(state.legal_activations())
+            .filter_map(|action| {
+                let mut after = state.clone();
+                after.apply(&action).ok()?;
+                after.resolve_stack();
+                if after.outcome.is_some_and(|outcome| outcome.winner == Some(me)) {
+                    return Some((i64::MAX, action));
+                }
+                // A cast spell leaves the hand; don't count the card itself against it
+                let gain = Self::advantage(&after, me) - before;
+                let gain = match action {
+                    Action::Play { .. } => gain + 1,
+                    _ => gain,
+                };
+                (gain > 0).then_some((gain, action))
+            })
+            .max_by_key(|(gain, _)| *gain)
+            .map(|(_, action)| action)
+    }
+
+    // Which ready creatures should attack
+    fn choose_attackers(state: &MatchState) -> Vec<usize> {
+        let own = state.side(state.active);
+        let enemy_side = state.active.other();
+        let enemy = state.side(enemy_side);
+        let ready = state.ready_attackers();
+        // Whoever would block `attacker` coming down lane `slot`, Guards and Flying included
+        let blocker = |slot: usize, attacker: &Unit| {
+            state
+                .blocker_for(enemy_side, slot, attacker)
+                .and_then(|blocker_slot| enemy.board[blocker_slot].as_ref())
+        };
+
+        // If the open lanes alone are lethal, swing with everything
+        let face_damage: i64 = ready
+            .iter()
+            .filter_map(|&slot| own.board[slot].as_ref().map(|unit| (slot, unit)))
+            .filter(|&(slot, unit)| blocker(slot, unit).is_none())
+            .map(|(_, unit)| i64::from(unit.attack))
+            .sum();
+        if face_damage >= i64::from(enemy.life) {
+            return ready;
+        }
+
+        ready
+            .into_iter()
+            .filter(|&slot| {
+                let Some(attacker) = &own.board[slot] else {
+                    return false;
+                };
+                let Some(blocker) = blocker(slot, attacker) else {
+                    return true;
+                };
+                let kills = attacker.kills(blocker);
+                let survives = !blocker.kills(attacker);
+                // Free kills, trades up, and free chip damage into a blocker that can't hit back
+                (kills && (survives || blocker.value() >= attacker.value()))
+                    || (survives && blocker.attack == 0)
+            })
+            .collect()
+    }
+}
+
+impl OpponentAi for GreedyAi {
+    fn choose_action(&mut self, state: &MatchState, _rng: &mut dyn RngCore) -> Action {
+        Self::choose_play(state)
+            .or_else(|| Self::choose_effect(state))
+            .unwrap_or_else(|| {
+                if state.stack.is_empty() {
+                    Action::Attack {
+                        slots: Self::choose_attackers(state),
+                    }
+                } else {
+                    Action::Pass
+                }
+            })
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::sync::Arc;
+    use cardigan_core::{CardData, CardId, CardInstance, CardStats, MatchRules};
+    use super::*;
+
+    // A ready vanilla creature
+    pub(crate) fn unit(attack: u32, health: u32) -> Unit {
+        let data = CardData {
+            id: "test".to_string(),
+            name: "Test".to_string(),
+            cost: 1,
+            stats: Some(CardStats { attack, health }),
+            rules_text: String::new(),
+            art: None,
+            tags: Vec::new(),
+            keywords: Vec::new(),
+            abilities: Vec::new(),
+        };
+        let mut unit = Unit::new(CardInstance { id: CardId(1), data: Arc::new(data) }).unwrap();
+        unit.can_attack = true;
+        unit
+    }
+
+    // A match between empty decks with life totals and boards at their limits
+    pub(crate) fn extreme_state() -> MatchState {
+        let mut state = MatchState::new(Vec::new(), Vec::new(), MatchRules::default());
+        state.player.life = i32::MAX;
+        state.opponent.life = i32::MIN;
+        for slot in 0..state.rules.board_size() {
+            state.player.board[slot] = Some(unit(u32::MAX, i32::MAX as u32));
+        }
+        state
+    }
+
+    #[test]
+    fn advantage_counts_life_and_stats_at_their_limits() {
+        let state = extreme_state();
+
+        let board = 5 * (i64::from(u32::MAX) + i64::from(i32::MAX));
+        let ahead = i64::from(i32::MAX) - i64::from(i32::MIN) + board;
+        assert_eq!(GreedyAi::advantage(&state, PlayerSide::Player), ahead);
+        assert_eq!(GreedyAi::advantage(&state, PlayerSide::Opponent), -ahead);
+    }
+
+    #[test]
+    fn huge_attackers_are_lethal_against_huge_life() {
+        let mut state = MatchState::new(Vec::new(), Vec::new(), MatchRules::default());
+        state.opponent.life = i32::MAX;
+        state.player.board[0] = Some(unit(u32::MAX, 1));
+        state.player.board[1] = Some(unit(1, 1));
+        state.opponent.board[1] = Some(unit(0, 5));
+
+        // Only the open lane hits, and it alone is lethal, so everything swings
+        assert_eq!(GreedyAi::choose_attackers(&state), vec![0, 1]);
+    }
+}
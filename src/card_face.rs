@@ -0,0 +1,343 @@
+use std::f32::consts::FRAC_PI_4;
+use bevy::prelude::*;
+use bevy::text::TextBounds;
+use cardigan_core::{CardData, Keyword};
+use crate::combat::StatText;
+use crate::gameplay::CardText;
+
+// Color of the cost gem; mana is the same for every faction
+const GEM_COLOR: Color = Color::srgb(0.2, 0.35, 0.75);
+
+// Dark ink for the name, type line and rules text
+const INK_COLOR: Color = Color::srgb(0.1, 0.1, 0.15);
+
+// The faction a card belongs to, from its tags; it picks the frame's colors
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Faction {
+    Flame,
+    Tide,
+    Grove,
+}
+
+impl Faction {
+    // The tag that puts a card in this faction
+    pub fn tag(self) -> &'static str {
+        match self {
+            Faction::Flame => "flame",
+            Faction::Tide => "tide",
+            Faction::Grove => "grove",
+        }
+    }
+
+    // The faction named by a card's tags, if any
+    pub fn of(card_data: &CardData) -> Option<Faction> {
+        [Faction::Flame, Faction::Tide, Faction::Grove]
+            .into_iter()
+            .find(|faction| card_data.tags.iter().any(|tag| tag == faction.tag()))
+    }
+}
+
+// Colors of a card frame
+#[derive(Clone, Copy)]
+pub struct CardTheme {
+    pub frame: Color,     // The card itself, around the panels
+    pub bar: Color,       // Name bar and type line
+    pub art: Color,       // Art window, behind the art or instead of it
+    pub text_box: Color,  // Rules text box and stat corners
+}
+
+impl CardTheme {
+    // The frame for a card: its faction's, or a plain one for cards without a faction
+    pub fn of(card_data: &CardData) -> Self {
+        match Faction::of(card_data) {
+            Some(Faction::Flame) => CardTheme {
+                frame: Color::srgb(0.62, 0.24, 0.16),
+                bar: Color::srgb(0.93, 0.78, 0.62),
+                art: Color::srgb(0.38, 0.14, 0.1),
+                text_box: Color::srgb(0.96, 0.9, 0.82),
+            },
+            Some(Faction::Tide) => CardTheme {
+                frame: Color::srgb(0.16, 0.36, 0.58),
+                bar: Color::srgb(0.75, 0.86, 0.93),
+                art: Color::srgb(0.1, 0.22, 0.36),
+                text_box: Color::srgb(0.88, 0.93, 0.96),
+            },
+            Some(Faction::Grove) => CardTheme {
+                frame: Color::srgb(0.22, 0.45, 0.2),
+                bar: Color::srgb(0.8, 0.9, 0.7),
+                art: Color::srgb(0.14, 0.28, 0.12),
+                text_box: Color::srgb(0.9, 0.95, 0.85),
+            },
+            None => CardTheme {
+                frame: Color::srgb(0.5, 0.48, 0.45),
+                bar: Color::srgb(0.85, 0.83, 0.8),
+                art: Color::srgb(0.3, 0.3, 0.32),
+                text_box: Color::srgb(0.93, 0.92, 0.9),
+            },
+        }
+    }
+}
+
+// The type line: "Creature" or "Spell", then the card's other tags, e.g. "Creature - Beast"
+pub fn type_line(card_data: &CardData) -> String {
+    let card_type = if card_data.stats.is_some() { "Creature" } else { "Spell" };
+    let subtypes: Vec<String> = card_data
+        .tags
+        .iter()
+        .filter(|tag| {
+            !matches!(tag.as_str(), "creature" | "spell")
+                && Faction::of(card_data).is_none_or(|faction| faction.tag() != tag.as_str())
+        })
+        .map(|tag| {
+            let mut letters = tag.chars();
+            letters
+                .next()
+                .map(|first| first.to_uppercase().chain(letters).collect())
+                .unwrap_or_default()
+        })
+        .collect();
+
+    if subtypes.is_empty() {
+        card_type.to_string()
+    } else {
+        format!("{} - {}", card_type, subtypes.join(" "))
+    }
+}
+
+// Letter and color of the badge a keyword gets on the card face
+fn keyword_badge(keyword: Keyword) -> (&'static str, Color) {
+    match keyword {
+        Keyword::Guard => ("G", Color::srgb(0.45, 0.5, 0.6)),
+        Keyword::Haste => ("H", Color::srgb(0.9, 0.5, 0.1)),
+        Keyword::Flying => ("F", Color::srgb(0.35, 0.65, 0.9)),
+        Keyword::Lifesteal => ("L", Color::srgb(0.75, 0.15, 0.25)),
+        Keyword::Deathtouch => ("D", Color::srgb(0.45, 0.2, 0.55)),
+        Keyword::Elusive => ("E", Color::srgb(0.2, 0.6, 0.55)),
+    }
+}
+
+// Spawn a card's face as children of the card entity, whose own sprite is the frame (see
+// `CardTheme::frame`). The border comes first (hover and feedback systems rely on that), then
+// from the top: cost gem, name bar, art window with keyword badges, type line, rules text box
+// and attack/health corners. Everything is laid out as a fraction of the card's size, so the
+// same face works in the hand and in a small board slot.
+pub fn spawn_card_face(
+    parent: &mut ChildSpawnerCommands,
+    card_data: &CardData,
+    card_size: Vec2,
+    asset_server: &AssetServer,
+) {
+    let theme = CardTheme::of(card_data);
+    let (width, height) = (card_size.x, card_size.y);
+    let margin = width * 0.05;
+    let panel_width = width - margin * 2.0;
+
+    // Card border (behind the card)
+    parent.spawn((
+        Sprite {
+            color: Color::srgb(0.3, 0.3, 0.4),
+            custom_size: Some(card_size + Vec2::splat(width * 0.03)),
+            ..default()
+        },
+        Transform::from_xyz(0.0, 0.0, -1.0),
+    ));
+
+    // Name bar along the top; the name leaves room for the cost gem on its left
+    let gem_size = width * 0.17;
+    let name_bar_height = height * 0.1;
+    let name_bar_y = height / 2.0 - margin - name_bar_height / 2.0;
+    parent.spawn((
+        Sprite {
+            color: theme.bar,
+            custom_size: Some(Vec2::new(panel_width, name_bar_height)),
+            ..default()
+        },
+        Transform::from_xyz(0.0, name_bar_y, 0.01),
+    ));
+    parent.spawn((
+        Text2d::new(&card_data.name),
+        TextFont {
+            font_size: height * 0.04,
+            ..default()
+        },
+        TextLayout::new_with_justify(Justify::Center),
+        TextBounds::from(Vec2::new(panel_width - gem_size, name_bar_height)),
+        TextColor(INK_COLOR),
+        Transform::from_xyz(gem_size / 2.0, name_bar_y, 0.03),
+        CardText,
+    ));
+
+    // Mana cost in a gem over the top-left corner
+    parent
+        .spawn((
+            Sprite {
+                color: GEM_COLOR,
+                custom_size: Some(Vec2::splat(gem_size)),
+                ..default()
+            },
+            Transform::from_xyz(-width / 2.0 + margin * 1.5, height / 2.0 - margin * 1.5, 0.04)
+                .with_rotation(Quat::from_rotation_z(FRAC_PI_4)),
+        ))
+        .with_children(|gem| {
+            gem.spawn((
+                Text2d::new(card_data.cost.to_string()),
+                TextFont {
+                    font_size: gem_size * 0.7,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+                // Undo the gem's turn so the number stands upright
+                Transform::from_xyz(0.0, 0.0, 0.01).with_rotation(Quat::from_rotation_z(-FRAC_PI_4)),
+            ));
+        });
+
+    // Art window under the name, filled with the card's art when it has some
+    let art_size = Vec2::new(panel_width, height * 0.38);
+    let art_top = name_bar_y - name_bar_height / 2.0 - margin / 2.0;
+    let art_y = art_top - art_size.y / 2.0;
+    parent.spawn((
+        Sprite {
+            color: theme.art,
+            custom_size: Some(art_size),
+            ..default()
+        },
+        Transform::from_xyz(0.0, art_y, 0.01),
+    ));
+    if let Some(art) = &card_data.art {
+        parent.spawn((
+            Sprite {
+                image: asset_server.load(art.clone()),
+                custom_size: Some(art_size),
+                ..default()
+            },
+            Transform::from_xyz(0.0, art_y, 0.02),
+        ));
+    }
+
+    // One badge per keyword, down the right edge of the art
+    let badge_size = width * 0.12;
+    for (i, keyword) in card_data.keywords.iter().enumerate() {
+        let (letter, color) = keyword_badge(*keyword);
+        parent
+            .spawn((
+                Sprite {
+                    color,
+                    custom_size: Some(Vec2::splat(badge_size)),
+                    ..default()
+                },
+                Transform::from_xyz(
+                    panel_width / 2.0 - badge_size * 0.7,
+                    art_top - badge_size * 0.7 - i as f32 * badge_size * 1.2,
+                    0.04,
+                ),
+            ))
+            .with_children(|badge| {
+                badge.spawn((
+                    Text2d::new(letter),
+                    TextFont {
+                        font_size: badge_size * 0.7,
+                        ..default()
+                    },
+                    TextColor(Color::WHITE),
+                    Transform::from_xyz(0.0, 0.0, 0.01),
+                ));
+            });
+    }
+
+    // Type line under the art
+    let type_line_height = height * 0.07;
+    let type_line_y = art_y - art_size.y / 2.0 - margin / 2.0 - type_line_height / 2.0;
+    parent.spawn((
+        Sprite {
+            color: theme.bar,
+            custom_size: Some(Vec2::new(panel_width, type_line_height)),
+            ..default()
+        },
+        Transform::from_xyz(0.0, type_line_y, 0.01),
+    ));
+    parent.spawn((
+        Text2d::new(type_line(card_data)),
+        TextFont {
+            font_size: height * 0.032,
+            ..default()
+        },
+        TextColor(INK_COLOR),
+        Transform::from_xyz(0.0, type_line_y, 0.03),
+    ));
+
+    // Rules text box down to the bottom edge, led by the keywords' names. The text keeps
+    // clear of the stat corners that sit over the box's bottom.
+    let stat_size = width * 0.2;
+    let rules_top = type_line_y - type_line_height / 2.0 - margin / 2.0;
+    let rules_bottom = -height / 2.0 + margin;
+    let rules_y = (rules_top + rules_bottom) / 2.0;
+    parent.spawn((
+        Sprite {
+            color: theme.text_box,
+            custom_size: Some(Vec2::new(panel_width, rules_top - rules_bottom)),
+            ..default()
+        },
+        Transform::from_xyz(0.0, rules_y, 0.01),
+    ));
+
+    let keyword_names: Vec<&str> = card_data.keywords.iter().map(|keyword| keyword.name()).collect();
+    let rules_text = [keyword_names.join(", "), card_data.rules_text.clone()]
+        .into_iter()
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n");
+    if !rules_text.is_empty() {
+        let text_height = rules_top - rules_bottom - stat_size / 2.0 - margin;
+        parent.spawn((
+            Text2d::new(rules_text),
+            TextFont {
+                font_size: height * 0.032,
+                ..default()
+            },
+            TextLayout::new_with_justify(Justify::Center),
+            TextBounds::from(Vec2::new(panel_width - margin * 2.0, text_height)),
+            TextColor(INK_COLOR),
+            Transform::from_xyz(0.0, rules_top - margin / 2.0 - text_height / 2.0, 0.03),
+        ));
+    }
+
+    // Attack and health in the bottom corners
+    if let Some(stats) = card_data.stats {
+        let stat_x = width / 2.0 - stat_size * 0.45;
+        let stat_y = -height / 2.0 + stat_size * 0.45;
+        for (stat, value, x, color) in [
+            (StatText::Attack, stats.attack, -stat_x, Color::srgb(0.85, 0.65, 0.2)),
+            (StatText::Health, stats.health, stat_x, Color::srgb(0.75, 0.25, 0.2)),
+        ] {
+            parent
+                .spawn((
+                    Sprite {
+                        color,
+                        custom_size: Some(Vec2::splat(stat_size)),
+                        ..default()
+                    },
+                    Transform::from_xyz(x, stat_y, 0.04),
+                ))
+                .with_children(|corner| {
+                    corner.spawn((
+                        Sprite {
+                            color: theme.text_box,
+                            custom_size: Some(Vec2::splat(stat_size * 0.8)),
+                            ..default()
+                        },
+                        Transform::from_xyz(0.0, 0.0, 0.01),
+                    ));
+                });
+            parent.spawn((
+                Text2d::new(value.to_string()),
+                TextFont {
+                    font_size: stat_size * 0.6,
+                    ..default()
+                },
+                TextColor(INK_COLOR),
+                Transform::from_xyz(x, stat_y, 0.06),
+                stat,
+            ));
+        }
+    }
+}
@@ -0,0 +1,244 @@
+use std::collections::BTreeMap;
+
+use bevy::asset::{io::Reader, AssetLoader, LoadContext, LoadedFolder};
+use bevy::prelude::*;
+use cardigan_core::{Ability, CardData, Effect, Target, Trigger};
+use thiserror::Error;
+
+// Folder (relative to `assets/`) that card definitions are loaded from
+pub const CARDS_FOLDER: &str = "cards";
+
+// Plugin initializer for card definition loading
+pub fn init_card_systems(app: &mut App) {
+    app.init_asset::<CardAsset>()
+        .register_asset_loader(CardDataLoader)
+        .init_resource::<CardLibrary>()
+        .add_systems(Startup, load_card_definitions)
+        .add_systems(Update, update_card_library);
+}
+
+// A card definition as a Bevy asset; the definition itself belongs to the rules crate
+#[derive(Asset, TypePath, Clone, Debug)]
+pub struct CardAsset(pub CardData);
+
+// Library of every card definition found under `assets/cards/`, keyed by card id.
+// A BTreeMap keeps iteration order stable, so anything built from the library is reproducible.
+#[derive(Resource, Default)]
+pub struct CardLibrary {
+    pub folder: Handle<LoadedFolder>,
+    pub cards: BTreeMap<String, CardData>,
+}
+
+impl CardLibrary {
+    pub fn get(&self, id: &str) -> Option<&CardData> {
+        self.cards.get(id)
+    }
+
+    pub fn contains(&self, id: &str) -> bool {
+        self.cards.contains_key(id)
+    }
+}
+
+// Errors produced while loading a `.card.ron` file
+#[derive(Debug, Error)]
+pub enum CardLoadError {
+    #[error("could not read card file: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("{path}:{line}:{column}: {message}")]
+    Parse {
+        path: String,
+        line: usize,
+        column: usize,
+        message: ron::Error,
+    },
+    #[error("{path}: {message}")]
+    Invalid { path: String, message: String },
+    #[error("{path}: could not load script {script}: {message}")]
+    Script {
+        path: String,
+        script: String,
+        message: String,
+    },
+}
+
+// Asset loader for card definition files (`*.card.ron`)
+#[derive(Default)]
+pub struct CardDataLoader;
+
+impl AssetLoader for CardDataLoader {
+    type Asset = CardAsset;
+    type Settings = ();
+    type Error = CardLoadError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let path = load_context.path().display().to_string();
+
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+
+        let mut card = ron::de::from_bytes::<CardData>(&bytes).map_err(|error| CardLoadError::Parse {
+            path: path.clone(),
+            line: error.position.line,
+            column: error.position.col,
+            message: error.code,
+        })?;
+
+        validate_card(&card).map_err(|message| CardLoadError::Invalid {
+            path: path.clone(),
+            message,
+        })?;
+
+        // Script sources travel with the card; reading them through the load context means
+        // editing a script reloads the card
+        for (script, source) in card.scripts_mut() {
+            let script_error = |message: String| CardLoadError::Script {
+                path: path.clone(),
+                script: script.to_string(),
+                message,
+            };
+            let bytes = load_context
+                .read_asset_bytes(script.to_string())
+                .await
+                .map_err(|error| script_error(error.to_string()))?;
+            let text = String::from_utf8(bytes).map_err(|error| script_error(error.to_string()))?;
+            *source = text.into();
+        }
+
+        Ok(CardAsset(card))
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["card.ron"]
+    }
+}
+
+// Checks the parts of a card definition that the RON schema can't express
+fn validate_card(card: &CardData) -> Result<(), String> {
+    if card.id.trim().is_empty() {
+        return Err("card id must not be empty".to_string());
+    }
+
+    if card.id.chars().any(char::is_whitespace) {
+        return Err(format!("card id `{}` must not contain whitespace", card.id));
+    }
+
+    if card.name.trim().is_empty() {
+        return Err(format!("card `{}` has an empty name", card.id));
+    }
+
+    // Keywords only mean something on the board
+    if card.stats.is_none() && !card.keywords.is_empty() {
+        return Err(format!("spell `{}` can't have keywords", card.id));
+    }
+    for (i, keyword) in card.keywords.iter().enumerate() {
+        if card.keywords[..i].contains(keyword) {
+            return Err(format!("card `{}` lists the keyword {} twice", card.id, keyword.name()));
+        }
+    }
+
+    // Attack, health, damage and healing are all dealt out as i32s once the card is in play, so
+    // none of them may be larger than i32::MAX
+    if let Some(stats) = card.stats {
+        for (stat, value) in [("attack", stats.attack), ("health", stats.health)] {
+            if i32::try_from(value).is_err() {
+                return Err(format!("card `{}` has {} {}, the most is {}", card.id, value, stat, i32::MAX));
+            }
+        }
+    }
+    for effect in card.abilities.iter().flat_map(Ability::effects) {
+        if let Effect::Damage { amount, .. } | Effect::Heal { amount, .. } = *effect
+            && i32::try_from(amount).is_err()
+        {
+            return Err(format!("card `{}` has an effect amount of {}, the most is {}", card.id, amount, i32::MAX));
+        }
+    }
+
+    // Spells never reach the board, so only their on-play abilities could ever happen
+    let board_only = card.abilities.iter().any(|ability| {
+        !matches!(ability, Ability::Triggered { trigger: Trigger::OnPlay, .. })
+    });
+    if card.stats.is_none() && board_only {
+        return Err(format!("spell `{}` can only have on-play abilities", card.id));
+    }
+
+    // A chosen target is picked once, when the card is played or the ability used, so every
+    // effect that shares the choice has to want the same kind of target
+    let mut play_target = None;
+    for ability in &card.abilities {
+        let mut chosen = ability.effects().iter().filter_map(Effect::target).filter(Target::is_chosen);
+        let Some(first) = chosen.next() else {
+            continue;
+        };
+        if chosen.any(|target| target != first) {
+            return Err(format!("card `{}` has an ability with more than one kind of chosen target", card.id));
+        }
+        match ability {
+            Ability::Triggered { trigger: Trigger::OnPlay, .. } => {
+                if play_target.is_some_and(|target| target != first) {
+                    return Err(format!("card `{}` has on-play abilities with different chosen targets", card.id));
+                }
+                play_target = Some(first);
+            }
+            Ability::Triggered { .. } => {
+                return Err(format!(
+                    "card `{}` can only choose targets when played or for activated abilities",
+                    card.id
+                ));
+            }
+            Ability::Activated { .. } => {}
+        }
+    }
+
+    Ok(())
+}
+
+// Start loading every card definition (runs once at startup)
+pub fn load_card_definitions(asset_server: Res<AssetServer>, mut library: ResMut<CardLibrary>) {
+    library.folder = asset_server.load_folder(CARDS_FOLDER);
+}
+
+// Rebuild the card library whenever a card definition is added, changed or removed.
+// Files that fail to load never reach `Assets<CardAsset>`; the asset server logs their errors.
+// Of several files with the same card id, the first by path is used and the others are skipped.
+pub fn update_card_library(
+    mut events: MessageReader<AssetEvent<CardAsset>>,
+    card_assets: Res<Assets<CardAsset>>,
+    asset_server: Res<AssetServer>,
+    mut library: ResMut<CardLibrary>,
+) {
+    if events.read().count() == 0 {
+        return;
+    }
+
+    // Go through the files in path order, so that when two define the same id the same one
+    // wins every time, whatever order the asset server loaded them in
+    let mut loaded: Vec<(String, &CardData)> = card_assets
+        .iter()
+        .map(|(asset_id, CardAsset(card))| {
+            let path = asset_server
+                .get_path(asset_id)
+                .map(|path| path.to_string())
+                .unwrap_or_default();
+            (path, card)
+        })
+        .collect();
+    loaded.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    let mut cards = BTreeMap::new();
+    let mut paths: BTreeMap<&str, &str> = BTreeMap::new();
+    for (path, card) in &loaded {
+        if let Some(kept) = paths.get(card.id.as_str()) {
+            warn!("Duplicate card id `{}`: skipping {} (already defined in {})", card.id, path, kept);
+            continue;
+        }
+        paths.insert(&card.id, path);
+        cards.insert(card.id.clone(), (*card).clone());
+    }
+
+    library.cards = cards;
+}
@@ -0,0 +1,302 @@
+use bevy::prelude::*;
+use cardigan_core::PlayerSide;
+use crate::{GameState, InMatch};
+use crate::gameplay::{Card, CardZone, GameEntity, GameplayState};
+use crate::picking::topmost_at;
+use crate::piles::pile_browser_closed;
+use crate::startup::VirtualResolution;
+use crate::turn::TurnPhase;
+
+// How far the glow behind a creature picked to attack reaches past its edges
+const ATTACKER_HIGHLIGHT_MARGIN: f32 = 24.0;
+
+// Plugin initializer for combat systems
+pub fn init_combat_systems(app: &mut App) {
+    app.add_systems(OnEnter(InMatch), setup_life_ui)
+        .add_systems(OnEnter(TurnPhase::Combat), begin_combat)
+        .add_systems(OnExit(InMatch), cleanup_combat)
+        .add_systems(
+            Update,
+            (
+                creature_stats_text_system,
+                life_label_system,
+                resolve_combat.run_if(in_state(TurnPhase::Combat).and(resource_exists::<DeclaredAttackers>)),
+                (attacker_toggle_system.run_if(pile_browser_closed), attacker_highlight_system)
+                    .chain()
+                    .run_if(resource_exists::<AttackerSelection>),
+                clear_attacker_highlights_system.run_if(resource_removed::<AttackerSelection>),
+            )
+                .run_if(in_state(GameState::Playing)),
+        );
+}
+
+// Slots whose creatures attack this combat. The opponent declares them before combat starts;
+// the player confirms an `AttackerSelection`. Combat resolves as soon as they are declared.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct DeclaredAttackers {
+    pub slots: Vec<usize>,
+}
+
+// The player is choosing attackers at the start of their combat: clicking a ready creature
+// picks or unpicks it, and the End Turn button declares the picked ones
+#[derive(Resource, Debug, Clone, Default)]
+pub struct AttackerSelection {
+    pub slots: Vec<usize>,
+}
+
+// Marker for the glow behind a creature that can attack, bright once it's picked
+#[derive(Component)]
+pub struct AttackerHighlight;
+
+// Which stat a card's corner text shows
+#[derive(Component, Clone, Copy, PartialEq, Eq)]
+pub enum StatText {
+    Attack,
+    Health,
+}
+
+// Component to mark a player's life label
+#[derive(Component)]
+pub struct LifeLabel {
+    pub side: PlayerSide,
+}
+
+// Setup life labels on the left edge, next to the mana labels. They react to the mouse so
+// players can be picked as targets.
+pub fn setup_life_ui(mut commands: Commands) {
+    for (side, top_margin) in [(PlayerSide::Opponent, -110.0), (PlayerSide::Player, 60.0)] {
+        commands.spawn((
+            Text::new(""),
+            TextFont {
+                font_size: 28.0,
+                ..default()
+            },
+            TextColor(Color::srgb(0.95, 0.55, 0.5)),
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(20.0),
+                top: Val::Percent(50.0),
+                margin: UiRect::top(Val::Px(top_margin)),
+                padding: UiRect::axes(Val::Px(8.0), Val::Px(4.0)),
+                ..default()
+            },
+            Interaction::default(),
+            BackgroundColor(Color::NONE),
+            LifeLabel { side },
+            GameEntity,
+        ));
+    }
+}
+
+// Start combat. The opponent's attackers were declared when it ended its main phase; the player
+// now picks theirs, unless none of their creatures could attack. Without a declaration,
+// nothing attacks.
+pub fn begin_combat(
+    mut commands: Commands,
+    declared_attackers: Option<Res<DeclaredAttackers>>,
+    gameplay_state: Res<GameplayState>,
+) {
+    if declared_attackers.is_some() {
+        return;
+    }
+
+    if gameplay_state.active == PlayerSide::Player && !gameplay_state.ready_attackers().is_empty() {
+        commands.insert_resource(AttackerSelection::default());
+    } else {
+        commands.insert_resource(DeclaredAttackers::default());
+    }
+}
+
+// Every declared creature of the active player attacks down its lane; the rules work out the
+// damage and send the dead to the graveyard. The phase ends once their death triggers have
+// resolved.
+pub fn resolve_combat(
+    mut commands: Commands,
+    declared_attackers: Res<DeclaredAttackers>,
+    mut gameplay_state: ResMut<GameplayState>,
+) {
+    commands.remove_resource::<DeclaredAttackers>();
+
+    gameplay_state.resolve_combat(&declared_attackers.slots);
+}
+
+// Clicking one of the player's ready creatures while attackers are being chosen picks it to
+// attack, or unpicks it
+pub fn attacker_toggle_system(
+    card_query: Query<(&CardZone, &Transform, &Sprite), With<Card>>,
+    mut selection: ResMut<AttackerSelection>,
+    gameplay_state: Res<GameplayState>,
+    mouse_button: Res<ButtonInput<MouseButton>>,
+    window_query: Query<&Window>,
+    resolution: Res<VirtualResolution>,
+) {
+    if !mouse_button.just_pressed(MouseButton::Left) {
+        return;
+    }
+
+    let Some(window) = window_query.iter().next() else {
+        return;
+    };
+
+    let Some(cursor_world_pos) = resolution.cursor_position(window) else {
+        return;
+    };
+
+    let board_cards = card_query.iter().filter_map(|(zone, transform, sprite)| {
+        let (PlayerSide::Player, slot) = zone_slot(zone)? else {
+            return None;
+        };
+        Some((slot, transform, sprite))
+    });
+    let Some(slot) = topmost_at(cursor_world_pos, board_cards) else {
+        return;
+    };
+    if !gameplay_state.ready_attackers().contains(&slot) {
+        return;
+    }
+
+    match selection.slots.iter().position(|picked| *picked == slot) {
+        Some(index) => {
+            selection.slots.remove(index);
+        }
+        None => {
+            selection.slots.push(slot);
+            selection.slots.sort_unstable();
+        }
+    }
+}
+
+// Put a glow behind each creature that can attack: faint until it's picked, bright after
+pub fn attacker_highlight_system(
+    mut commands: Commands,
+    selection: Res<AttackerSelection>,
+    gameplay_state: Res<GameplayState>,
+    card_query: Query<(Entity, &Card, &CardZone, Option<&Children>)>,
+    mut highlight_query: Query<&mut Sprite, With<AttackerHighlight>>,
+) {
+    let ready = gameplay_state.ready_attackers();
+
+    for (entity, card, zone, children) in card_query.iter() {
+        let slot = match zone_slot(zone) {
+            Some((PlayerSide::Player, slot)) if ready.contains(&slot) => slot,
+            _ => continue,
+        };
+        let color = if selection.slots.contains(&slot) {
+            Color::srgba(0.95, 0.25, 0.2, 0.9)
+        } else {
+            Color::srgba(0.95, 0.25, 0.2, 0.3)
+        };
+
+        let highlight = children
+            .into_iter()
+            .flat_map(|children| children.iter())
+            .find(|child| highlight_query.contains(*child));
+        match highlight {
+            Some(highlight) => {
+                if let Ok(mut sprite) = highlight_query.get_mut(highlight)
+                    && sprite.color != color
+                {
+                    sprite.color = color;
+                }
+            }
+            None => {
+                commands.entity(entity).with_children(|parent| {
+                    parent.spawn((
+                        Sprite {
+                            color,
+                            custom_size: Some(card.base_size + Vec2::splat(ATTACKER_HIGHLIGHT_MARGIN)),
+                            ..default()
+                        },
+                        Transform::from_xyz(0.0, 0.0, -1.5),
+                        AttackerHighlight,
+                    ));
+                });
+            }
+        }
+    }
+}
+
+// Remove the glows once the attackers are declared
+pub fn clear_attacker_highlights_system(
+    mut commands: Commands,
+    highlight_query: Query<Entity, With<AttackerHighlight>>,
+) {
+    for entity in highlight_query.iter() {
+        commands.entity(entity).despawn();
+    }
+}
+
+// Forget a combat that was still being declared when the match ended
+pub fn cleanup_combat(mut commands: Commands) {
+    commands.remove_resource::<AttackerSelection>();
+    commands.remove_resource::<DeclaredAttackers>();
+}
+
+// Keep the attack/health corners of each card on the board in sync with its creature stats
+pub fn creature_stats_text_system(
+    card_query: Query<(&CardZone, &Children), With<Card>>,
+    mut text_query: Query<(&StatText, &mut Text2d, &mut TextColor)>,
+    gameplay_state: Res<GameplayState>,
+) {
+    for (zone, children) in card_query.iter() {
+        let Some((side, slot)) = zone_slot(zone) else {
+            continue;
+        };
+        let Some(Some(creature)) = gameplay_state.side(side).board.get(slot) else {
+            continue;
+        };
+        for child in children.iter() {
+            let Ok((stat, mut text, mut color)) = text_query.get_mut(child) else {
+                continue;
+            };
+            let (value, value_color) = match stat {
+                StatText::Attack => (creature.attack.to_string(), Color::srgb(0.1, 0.1, 0.15)),
+                // Damaged creatures show their health in red
+                StatText::Health if creature.health < creature.max_health => {
+                    (creature.health.max(0).to_string(), Color::srgb(0.7, 0.1, 0.1))
+                }
+                StatText::Health => (creature.health.to_string(), Color::srgb(0.1, 0.1, 0.15)),
+            };
+            if text.0 != value {
+                text.0 = value;
+            }
+            if color.0 != value_color {
+                color.0 = value_color;
+            }
+        }
+    }
+}
+
+// Keep the life labels in sync with the gameplay state
+pub fn life_label_system(
+    mut label_query: Query<(&LifeLabel, &mut Text)>,
+    gameplay_state: Res<GameplayState>,
+) {
+    for (label, mut text) in label_query.iter_mut() {
+        let who = match label.side {
+            PlayerSide::Player => "You",
+            PlayerSide::Opponent => "Opponent",
+        };
+        let value = format!("{}: {} life", who, gameplay_state.side(label.side).life);
+        if text.0 != value {
+            text.0 = value;
+        }
+    }
+}
+
+// Which side's play area slot a zone is, if it is one
+pub fn zone_slot(zone: &CardZone) -> Option<(PlayerSide, usize)> {
+    match *zone {
+        CardZone::PlayerPlayArea { slot } => Some((PlayerSide::Player, slot)),
+        CardZone::OpponentPlayArea { slot } => Some((PlayerSide::Opponent, slot)),
+        _ => None,
+    }
+}
+
+// The play area zone for a side's slot
+pub fn slot_zone(side: PlayerSide, slot: usize) -> CardZone {
+    match side {
+        PlayerSide::Player => CardZone::PlayerPlayArea { slot },
+        PlayerSide::Opponent => CardZone::OpponentPlayArea { slot },
+    }
+}
@@ -0,0 +1,464 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+use bevy::asset::{io::Reader, AssetLoader, LoadContext};
+use bevy::prelude::*;
+use cardigan_core::CardData;
+use serde::Deserialize;
+use thiserror::Error;
+use crate::cards::CardLibrary;
+
+// Deck lists used by each player (relative to `assets/`)
+pub const PLAYER_DECK_PATH: &str = "decks/flame.deck.ron";
+pub const OPPONENT_DECK_PATH: &str = "decks/tide.deck.ron";
+
+// Plugin initializer for deck list loading
+pub fn init_deck_systems(app: &mut App) {
+    let rules = DeckRules::default();
+
+    app.init_asset::<DeckList>()
+        .register_asset_loader(DeckListLoader { rules: rules.clone() })
+        .insert_resource(rules)
+        .add_systems(Startup, load_player_decks)
+        .add_systems(Update, check_deck_card_ids);
+}
+
+// Deck list asset, loaded from `assets/decks/*.deck.ron`
+#[derive(Asset, TypePath, Clone, Debug, Deserialize)]
+pub struct DeckList {
+    pub name: String,
+    pub cards: Vec<DeckEntry>,
+    #[serde(default)]
+    pub sideboard: Vec<DeckEntry>,
+}
+
+// A card id and how many copies of it the list contains
+#[derive(Clone, Debug, Deserialize)]
+pub struct DeckEntry {
+    pub card: String,
+    pub count: u32,
+}
+
+impl DeckList {
+    // Number of cards in the main deck
+    pub fn size(&self) -> usize {
+        self.cards.iter().map(|entry| entry.count as usize).sum()
+    }
+
+    pub fn sideboard_size(&self) -> usize {
+        self.sideboard.iter().map(|entry| entry.count as usize).sum()
+    }
+
+    // Check size and copy limits; these don't need the card library
+    pub fn validate(&self, rules: &DeckRules) -> Result<(), DeckErrors> {
+        let mut errors = Vec::new();
+
+        let size = self.size();
+        if size < rules.min_size {
+            errors.push(DeckError::TooFewCards { size, min: rules.min_size });
+        }
+        if size > rules.max_size {
+            errors.push(DeckError::TooManyCards { size, max: rules.max_size });
+        }
+
+        let sideboard_size = self.sideboard_size();
+        if sideboard_size > rules.max_sideboard_size {
+            errors.push(DeckError::SideboardTooLarge {
+                size: sideboard_size,
+                max: rules.max_sideboard_size,
+            });
+        }
+
+        // Copy limits apply across the main deck and sideboard together
+        let mut copies: BTreeMap<&str, u32> = BTreeMap::new();
+        for entry in self.cards.iter().chain(self.sideboard.iter()) {
+            if entry.count == 0 {
+                errors.push(DeckError::ZeroCount { card: entry.card.clone() });
+            }
+            *copies.entry(entry.card.as_str()).or_default() += entry.count;
+        }
+        for (card, count) in copies {
+            if count > rules.max_copies {
+                errors.push(DeckError::TooManyCopies {
+                    card: card.to_string(),
+                    count,
+                    max: rules.max_copies,
+                });
+            }
+        }
+
+        DeckErrors::result(errors)
+    }
+
+    // Card ids in the list that aren't in the card library
+    pub fn unknown_cards(&self, library: &CardLibrary) -> Vec<DeckError> {
+        let unknown: BTreeSet<&str> = self
+            .cards
+            .iter()
+            .chain(self.sideboard.iter())
+            .map(|entry| entry.card.as_str())
+            .filter(|card| !library.contains(card))
+            .collect();
+
+        unknown
+            .into_iter()
+            .map(|card| DeckError::UnknownCard { card: card.to_string() })
+            .collect()
+    }
+
+    // Expand the main deck into card data, one entry per copy, in list order
+    pub fn resolve(&self, library: &CardLibrary) -> Result<Vec<CardData>, DeckErrors> {
+        DeckErrors::result(self.unknown_cards(library))?;
+
+        Ok(self
+            .cards
+            .iter()
+            .flat_map(|entry| {
+                let card = library.get(&entry.card).cloned();
+                std::iter::repeat_n(card, entry.count as usize).flatten()
+            })
+            .collect())
+    }
+}
+
+// Deck construction rules, checked when a deck list is loaded
+#[derive(Resource, Clone, Debug)]
+pub struct DeckRules {
+    pub min_size: usize,
+    pub max_size: usize,
+    pub max_copies: u32,
+    pub max_sideboard_size: usize,
+}
+
+impl Default for DeckRules {
+    fn default() -> Self {
+        Self {
+            min_size: 20,
+            max_size: 40,
+            max_copies: 3,
+            max_sideboard_size: 10,
+        }
+    }
+}
+
+// A single rule a deck list breaks
+#[derive(Debug, Clone, Error)]
+pub enum DeckError {
+    #[error("deck has {size} cards, the minimum is {min}")]
+    TooFewCards { size: usize, min: usize },
+    #[error("deck has {size} cards, the maximum is {max}")]
+    TooManyCards { size: usize, max: usize },
+    #[error("sideboard has {size} cards, the maximum is {max}")]
+    SideboardTooLarge { size: usize, max: usize },
+    #[error("`{card}` appears {count} times, the limit is {max}")]
+    TooManyCopies { card: String, count: u32, max: u32 },
+    #[error("`{card}` has a count of 0")]
+    ZeroCount { card: String },
+    #[error("unknown card id `{card}`")]
+    UnknownCard { card: String },
+}
+
+// Every rule a deck list breaks, so a designer can fix them all in one go
+#[derive(Debug, Clone)]
+pub struct DeckErrors(pub Vec<DeckError>);
+
+impl DeckErrors {
+    fn result(errors: Vec<DeckError>) -> Result<(), DeckErrors> {
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(DeckErrors(errors))
+        }
+    }
+}
+
+impl fmt::Display for DeckErrors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let messages: Vec<String> = self.0.iter().map(ToString::to_string).collect();
+        write!(f, "{}", messages.join("; "))
+    }
+}
+
+impl std::error::Error for DeckErrors {}
+
+// Errors produced while loading a `.deck.ron` file
+#[derive(Debug, Error)]
+pub enum DeckLoadError {
+    #[error("could not read deck file: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("{path}:{line}:{column}: {message}")]
+    Parse {
+        path: String,
+        line: usize,
+        column: usize,
+        message: ron::Error,
+    },
+    #[error("{path}: {errors}")]
+    Invalid { path: String, errors: DeckErrors },
+}
+
+// Asset loader for deck list files (`*.deck.ron`)
+pub struct DeckListLoader {
+    pub rules: DeckRules,
+}
+
+impl AssetLoader for DeckListLoader {
+    type Asset = DeckList;
+    type Settings = ();
+    type Error = DeckLoadError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let path = load_context.path().display().to_string();
+
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+
+        let deck = ron::de::from_bytes::<DeckList>(&bytes).map_err(|error| DeckLoadError::Parse {
+            path: path.clone(),
+            line: error.position.line,
+            column: error.position.col,
+            message: error.code,
+        })?;
+
+        deck.validate(&self.rules)
+            .map_err(|errors| DeckLoadError::Invalid { path, errors })?;
+
+        Ok(deck)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["deck.ron"]
+    }
+}
+
+// Handles to the deck list each player brings to a match
+#[derive(Resource)]
+pub struct PlayerDecks {
+    pub player: Handle<DeckList>,
+    pub opponent: Handle<DeckList>,
+}
+
+// Why a player's deck list can't be played with
+#[derive(Debug, Clone, Error)]
+pub enum DeckUnavailable {
+    #[error("deck list {path} failed to load")]
+    Failed { path: String },
+    #[error("deck list {path} is still loading")]
+    Loading { path: String },
+    #[error("deck \"{name}\" can't be used: {errors}")]
+    Invalid { name: String, errors: DeckErrors },
+}
+
+// The decks both players start the next match with, built from their lists when the match is
+// started from the menu. A rematch reuses them.
+#[derive(Resource, Clone)]
+pub struct MatchDecks {
+    pub player: Vec<CardData>,
+    pub opponent: Vec<CardData>,
+}
+
+impl MatchDecks {
+    // Build both players' decks, failing if either list is missing or refers to cards that
+    // don't exist, so a match never starts with a short or empty deck
+    pub fn build(
+        player_decks: &PlayerDecks,
+        deck_lists: &Assets<DeckList>,
+        library: &CardLibrary,
+        asset_server: &AssetServer,
+    ) -> Result<Self, DeckUnavailable> {
+        Ok(Self {
+            player: build_deck(&player_decks.player, deck_lists, library, asset_server)?,
+            opponent: build_deck(&player_decks.opponent, deck_lists, library, asset_server)?,
+        })
+    }
+}
+
+// Build a player's deck from their list
+pub fn build_deck(
+    handle: &Handle<DeckList>,
+    deck_lists: &Assets<DeckList>,
+    library: &CardLibrary,
+    asset_server: &AssetServer,
+) -> Result<Vec<CardData>, DeckUnavailable> {
+    let Some(deck_list) = deck_lists.get(handle) else {
+        let path = handle.path().map(|path| path.to_string()).unwrap_or_default();
+        return Err(if asset_server.load_state(handle).is_failed() {
+            DeckUnavailable::Failed { path }
+        } else {
+            DeckUnavailable::Loading { path }
+        });
+    };
+
+    deck_list.resolve(library).map_err(|errors| DeckUnavailable::Invalid {
+        name: deck_list.name.clone(),
+        errors,
+    })
+}
+
+// Start loading both players' deck lists (runs once at startup)
+pub fn load_player_decks(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.insert_resource(PlayerDecks {
+        player: asset_server.load(PLAYER_DECK_PATH),
+        opponent: asset_server.load(OPPONENT_DECK_PATH),
+    });
+}
+
+// Report card ids that don't exist once both the deck list and the library are loaded.
+// Size and copy limits are checked by `DeckListLoader`, since they don't need the library.
+pub fn check_deck_card_ids(
+    mut events: MessageReader<AssetEvent<DeckList>>,
+    deck_lists: Res<Assets<DeckList>>,
+    library: Res<CardLibrary>,
+    asset_server: Res<AssetServer>,
+) {
+    let decks_changed = events.read().count() > 0;
+    if !decks_changed && !library.is_changed() {
+        return;
+    }
+
+    if library.cards.is_empty() {
+        return;
+    }
+
+    for (asset_id, deck_list) in deck_lists.iter() {
+        let errors = deck_list.unknown_cards(&library);
+        if errors.is_empty() {
+            continue;
+        }
+
+        let path = asset_server
+            .get_path(asset_id)
+            .map(|path| path.to_string())
+            .unwrap_or_default();
+        error!("{}: {}", path, DeckErrors(errors));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cardigan_core::CardData;
+    use super::*;
+
+    fn entry(card: &str, count: u32) -> DeckEntry {
+        DeckEntry { card: card.to_string(), count }
+    }
+
+    // `size` cards, three copies of each id at most
+    fn filler(size: u32) -> Vec<DeckEntry> {
+        (0..size.div_ceil(3))
+            .map(|index| entry(&format!("filler{index}"), (size - index * 3).min(3)))
+            .collect()
+    }
+
+    fn list(cards: Vec<DeckEntry>, sideboard: Vec<DeckEntry>) -> DeckList {
+        DeckList { name: "Test".to_string(), cards, sideboard }
+    }
+
+    fn errors(deck: &DeckList) -> Vec<DeckError> {
+        deck.validate(&DeckRules::default()).err().map(|errors| errors.0).unwrap_or_default()
+    }
+
+    fn library(ids: &[&str]) -> CardLibrary {
+        let card = |id: &str| CardData {
+            id: id.to_string(),
+            name: id.to_string(),
+            cost: 1,
+            stats: None,
+            rules_text: String::new(),
+            art: None,
+            tags: Vec::new(),
+            keywords: Vec::new(),
+            abilities: Vec::new(),
+        };
+        CardLibrary {
+            cards: ids.iter().map(|&id| (id.to_string(), card(id))).collect(),
+            ..default()
+        }
+    }
+
+    #[test]
+    fn a_deck_within_the_rules_is_valid() {
+        assert!(errors(&list(filler(20), Vec::new())).is_empty());
+        assert!(errors(&list(filler(40), vec![entry("spare", 3)])).is_empty());
+    }
+
+    #[test]
+    fn the_main_deck_must_have_20_to_40_cards() {
+        assert!(matches!(errors(&list(filler(19), Vec::new()))[..], [DeckError::TooFewCards { size: 19, min: 20 }]));
+        assert!(matches!(errors(&list(filler(41), Vec::new()))[..], [DeckError::TooManyCards { size: 41, max: 40 }]));
+    }
+
+    #[test]
+    fn copies_count_across_the_deck_and_sideboard() {
+        let mut cards = filler(18);
+        cards.push(entry("imp", 2));
+        let deck = list(cards, vec![entry("imp", 2)]);
+
+        let errors = errors(&deck);
+        assert!(matches!(&errors[..], [DeckError::TooManyCopies { card, count: 4, max: 3 }] if card == "imp"));
+    }
+
+    #[test]
+    fn the_sideboard_holds_at_most_10_cards() {
+        let deck = list(filler(20), vec![entry("a", 3), entry("b", 3), entry("c", 3), entry("d", 2)]);
+
+        assert!(matches!(errors(&deck)[..], [DeckError::SideboardTooLarge { size: 11, max: 10 }]));
+    }
+
+    #[test]
+    fn entries_need_at_least_one_copy() {
+        let mut cards = filler(20);
+        cards.push(entry("ghost", 0));
+
+        let errors = errors(&list(cards, Vec::new()));
+        assert!(matches!(&errors[..], [DeckError::ZeroCount { card }] if card == "ghost"));
+    }
+
+    #[test]
+    fn every_broken_rule_is_reported() {
+        let mut cards = filler(15);
+        cards.push(entry("imp", 4));
+        cards.push(entry("ghost", 0));
+        let deck = list(cards, vec![entry("a", 3), entry("b", 3), entry("c", 3), entry("d", 3)]);
+
+        let errors = errors(&deck);
+        assert_eq!(errors.len(), 4, "{}", DeckErrors(errors.clone()));
+        assert!(matches!(errors[0], DeckError::TooFewCards { size: 19, .. }));
+        assert!(matches!(errors[1], DeckError::SideboardTooLarge { size: 12, .. }));
+        assert!(matches!(errors[2], DeckError::ZeroCount { .. }));
+        assert!(matches!(errors[3], DeckError::TooManyCopies { count: 4, .. }));
+    }
+
+    #[test]
+    fn resolving_rejects_unknown_ids() {
+        let deck = list(vec![entry("imp", 2), entry("ghost", 1), entry("wisp", 1)], vec![entry("spare", 1)]);
+
+        let Err(errors) = deck.resolve(&library(&["imp"])) else {
+            panic!("unknown cards resolved");
+        };
+        let unknown: Vec<_> = errors
+            .0
+            .iter()
+            .map(|error| match error {
+                DeckError::UnknownCard { card } => card.as_str(),
+                other => panic!("unexpected error {other}"),
+            })
+            .collect();
+        assert_eq!(unknown, vec!["ghost", "spare", "wisp"]);
+    }
+
+    #[test]
+    fn resolving_expands_each_copy_in_list_order() {
+        let deck = list(vec![entry("imp", 2), entry("wisp", 1)], vec![entry("spare", 1)]);
+
+        let cards = deck.resolve(&library(&["imp", "wisp", "spare"])).unwrap();
+
+        let ids: Vec<_> = cards.iter().map(|card| card.id.as_str()).collect();
+        assert_eq!(ids, vec!["imp", "imp", "wisp"]);
+    }
+}
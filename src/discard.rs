@@ -0,0 +1,142 @@
+use bevy::prelude::*;
+use cardigan_core::PlayerSide;
+use crate::GameState;
+use crate::gameplay::{hovered_hand_card, Card, GameEntity, GameplayState, InHand};
+use crate::turn::TurnPhase;
+
+// Plugin initializer for discarding down to the maximum hand size at the end of the turn
+pub fn init_discard_systems(app: &mut App) {
+    app.add_systems(
+        Update,
+        (discard_prompt_system, discard_pick_system.run_if(player_must_discard))
+            .chain()
+            .run_if(in_state(GameState::Playing)),
+    );
+}
+
+// Marker component for the hand limit prompt
+#[derive(Component)]
+pub struct DiscardPrompt;
+
+// Component to mark the prompt's text, which counts the cards still to discard
+#[derive(Component)]
+pub struct DiscardLabel;
+
+// Whether the player's turn is ending and they hold more cards than they may keep
+fn must_discard(gameplay_state: &GameplayState, phase: TurnPhase) -> bool {
+    phase == TurnPhase::End
+        && gameplay_state.active == PlayerSide::Player
+        && gameplay_state.stack.is_empty()
+        && gameplay_state.hand_overflow(PlayerSide::Player) > 0
+}
+
+// Run condition: the player has to discard before their turn can end
+pub fn player_must_discard(
+    gameplay_state: Option<Res<GameplayState>>,
+    phase: Option<Res<State<TurnPhase>>>,
+) -> bool {
+    let (Some(gameplay_state), Some(phase)) = (gameplay_state, phase) else {
+        return false;
+    };
+    must_discard(&gameplay_state, *phase.get())
+}
+
+// Show the prompt while the player has to discard, counting down as they do
+pub fn discard_prompt_system(
+    mut commands: Commands,
+    gameplay_state: Res<GameplayState>,
+    phase: Res<State<TurnPhase>>,
+    prompt_query: Query<Entity, With<DiscardPrompt>>,
+    mut label_query: Query<&mut Text, With<DiscardLabel>>,
+) {
+    if !must_discard(&gameplay_state, *phase.get()) {
+        for entity in prompt_query.iter() {
+            commands.entity(entity).despawn();
+        }
+        return;
+    }
+
+    let value = format!(
+        "HAND LIMIT - discard {} more (max {})",
+        gameplay_state.hand_overflow(PlayerSide::Player),
+        gameplay_state.rules.max_hand_size
+    );
+    if !prompt_query.is_empty() {
+        for mut text in label_query.iter_mut() {
+            if text.0 != value {
+                text.0 = value.clone();
+            }
+        }
+        return;
+    }
+
+    // Above the hand, in the middle of the board
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                top: Val::Percent(40.0),
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+            DiscardPrompt,
+            GameEntity,
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn((
+                    Node {
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Center,
+                        padding: UiRect::all(Val::Px(16.0)),
+                        row_gap: Val::Px(8.0),
+                        border: UiRect::all(Val::Px(2.0)),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgba(0.12, 0.12, 0.17, 0.9)),
+                    BorderColor::from(Color::srgb(0.7, 0.45, 0.3)),
+                ))
+                .with_children(|parent| {
+                    parent.spawn((
+                        Text::new(value),
+                        TextFont {
+                            font_size: 30.0,
+                            ..default()
+                        },
+                        TextColor(Color::srgb(1.0, 0.85, 0.3)),
+                        DiscardLabel,
+                    ));
+
+                    parent.spawn((
+                        Text::new("Click cards in your hand to put them in your graveyard"),
+                        TextFont {
+                            font_size: 20.0,
+                            ..default()
+                        },
+                        TextColor(Color::srgb(0.7, 0.7, 0.75)),
+                    ));
+                });
+        });
+}
+
+// While the prompt is up, clicking a hand card discards it
+pub fn discard_pick_system(
+    card_query: Query<&Card, With<InHand>>,
+    mut gameplay_state: ResMut<GameplayState>,
+    mouse_button: Res<ButtonInput<MouseButton>>,
+) {
+    if !mouse_button.just_pressed(MouseButton::Left) {
+        return;
+    }
+
+    let Some(card_id) = hovered_hand_card(&card_query) else {
+        return;
+    };
+    let Some(hand_index) = gameplay_state.player.hand.iter().position(|hand_card| hand_card.id == card_id) else {
+        return;
+    };
+    if let Err(error) = gameplay_state.discard(PlayerSide::Player, hand_index) {
+        warn!("Couldn't discard card {}: {}", hand_index, error);
+    }
+}
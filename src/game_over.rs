@@ -0,0 +1,228 @@
+use bevy::prelude::*;
+use cardigan_core::{MatchEndReason, Outcome, PlayerSide};
+use crate::GameState;
+use crate::gameplay::GameplayState;
+use crate::rng::GameRng;
+
+// Plugin initializer for win/loss detection and the game over screen
+pub fn init_game_over_systems(app: &mut App) {
+    app.add_systems(OnEnter(GameState::GameOver), setup_game_over)
+        .add_systems(OnExit(GameState::GameOver), cleanup_game_over)
+        .add_systems(Update, check_match_over.run_if(in_state(GameState::Playing)))
+        .add_systems(
+            Update,
+            (game_over_button_system, game_over_button_interaction)
+                .run_if(in_state(GameState::GameOver)),
+        );
+}
+
+// Outcome of the last match; `winner` is None for a draw
+#[derive(Resource, Debug, Clone, Copy, Deref)]
+pub struct MatchResult(pub Outcome);
+
+// Marker component for game over screen entities
+#[derive(Component)]
+pub struct GameOverEntity;
+
+// Component for game over screen buttons
+#[derive(Component)]
+pub enum GameOverButton {
+    Rematch,
+    MainMenu,
+}
+
+// Show the game over screen once the rules say the match has ended
+pub fn check_match_over(
+    mut commands: Commands,
+    gameplay_state: Res<GameplayState>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    if let Some(outcome) = gameplay_state.outcome {
+        commands.insert_resource(MatchResult(outcome));
+        next_state.set(GameState::GameOver);
+    }
+}
+
+// Setup game over UI
+pub fn setup_game_over(mut commands: Commands, result: Res<MatchResult>, rng: Res<GameRng>) {
+    let title = match result.winner {
+        Some(PlayerSide::Player) => "VICTORY",
+        Some(PlayerSide::Opponent) => "DEFEAT",
+        None => "DRAW",
+    };
+
+    let reason = match (result.reason, result.winner) {
+        (MatchEndReason::LifeDepleted, None) => "Both players ran out of life",
+        (MatchEndReason::LifeDepleted, Some(PlayerSide::Player)) => "Your opponent ran out of life",
+        (MatchEndReason::LifeDepleted, Some(PlayerSide::Opponent)) => "You ran out of life",
+        (MatchEndReason::DeckedOut, Some(PlayerSide::Player)) => "Your opponent ran out of cards",
+        (MatchEndReason::DeckedOut, _) => "You ran out of cards",
+    };
+
+    // Root node for the game over screen
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                flex_direction: FlexDirection::Column,
+                ..default()
+            },
+            GameOverEntity,
+        ))
+        .with_children(|parent| {
+            // Title
+            parent.spawn((
+                Text::new(title),
+                TextFont {
+                    font_size: 80.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.9, 0.9, 0.95)),
+                Node {
+                    margin: UiRect::bottom(Val::Px(20.0)),
+                    ..default()
+                },
+            ));
+
+            // Reason the match ended
+            parent.spawn((
+                Text::new(reason),
+                TextFont {
+                    font_size: 30.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.7, 0.7, 0.75)),
+                Node {
+                    margin: UiRect::bottom(Val::Px(20.0)),
+                    ..default()
+                },
+            ));
+
+            // Match seed, so the game can be replayed
+            parent.spawn((
+                Text::new(format!("SEED: {}", rng.seed())),
+                TextFont {
+                    font_size: 24.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.7, 0.7, 0.75)),
+                Node {
+                    margin: UiRect::bottom(Val::Px(60.0)),
+                    ..default()
+                },
+            ));
+
+            // Rematch button
+            parent
+                .spawn((
+                    Button,
+                    Node {
+                        width: Val::Px(300.0),
+                        height: Val::Px(65.0),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        margin: UiRect::all(Val::Px(10.0)),
+                        border: UiRect::all(Val::Px(2.0)),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgb(0.15, 0.15, 0.2)),
+                    BorderColor::from(Color::srgb(0.4, 0.4, 0.5)),
+                    GameOverButton::Rematch,
+                ))
+                .with_children(|parent| {
+                    parent.spawn((
+                        Text::new("REMATCH"),
+                        TextFont {
+                            font_size: 40.0,
+                            ..default()
+                        },
+                        TextColor(Color::srgb(0.9, 0.9, 0.95)),
+                    ));
+                });
+
+            // Main Menu button
+            parent
+                .spawn((
+                    Button,
+                    Node {
+                        width: Val::Px(300.0),
+                        height: Val::Px(65.0),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        margin: UiRect::all(Val::Px(10.0)),
+                        border: UiRect::all(Val::Px(2.0)),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgb(0.15, 0.15, 0.2)),
+                    BorderColor::from(Color::srgb(0.4, 0.4, 0.5)),
+                    GameOverButton::MainMenu,
+                ))
+                .with_children(|parent| {
+                    parent.spawn((
+                        Text::new("MAIN MENU"),
+                        TextFont {
+                            font_size: 40.0,
+                            ..default()
+                        },
+                        TextColor(Color::srgb(0.9, 0.9, 0.95)),
+                    ));
+                });
+        });
+}
+
+// Cleanup game over entities
+pub fn cleanup_game_over(
+    mut commands: Commands,
+    game_over_entities: Query<Entity, With<GameOverEntity>>,
+) {
+    for entity in game_over_entities.iter() {
+        commands.entity(entity).despawn();
+    }
+}
+
+// Handle game over button interactions (hover effects)
+pub fn game_over_button_interaction(
+    mut interaction_query: Query<
+        (&Interaction, &mut BackgroundColor, &mut BorderColor),
+        (Changed<Interaction>, With<GameOverButton>),
+    >,
+) {
+    for (interaction, mut bg_color, mut border_color) in interaction_query.iter_mut() {
+        match *interaction {
+            Interaction::Pressed => {
+                *bg_color = BackgroundColor(Color::srgb(0.25, 0.25, 0.3));
+                *border_color = BorderColor::from(Color::srgb(0.6, 0.6, 0.7));
+            }
+            Interaction::Hovered => {
+                *bg_color = BackgroundColor(Color::srgb(0.2, 0.2, 0.25));
+                *border_color = BorderColor::from(Color::srgb(0.7, 0.7, 0.8));
+            }
+            Interaction::None => {
+                *bg_color = BackgroundColor(Color::srgb(0.15, 0.15, 0.2));
+                *border_color = BorderColor::from(Color::srgb(0.4, 0.4, 0.5));
+            }
+        }
+    }
+}
+
+// Handle game over button clicks
+pub fn game_over_button_system(
+    interaction_query: Query<(&Interaction, &GameOverButton), (Changed<Interaction>, With<Button>)>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    for (interaction, button) in interaction_query.iter() {
+        if *interaction == Interaction::Pressed {
+            match button {
+                GameOverButton::Rematch => {
+                    next_state.set(GameState::Playing);
+                }
+                GameOverButton::MainMenu => {
+                    next_state.set(GameState::Menu);
+                }
+            }
+        }
+    }
+}
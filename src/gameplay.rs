@@ -1,20 +1,50 @@
+use std::collections::HashMap;
+use std::sync::Arc;
 use bevy::prelude::*;
-use crate::{GameState, CardConfig, CardData};
+use cardigan_core::{CardData, CardId, CardInstance, MatchRules, MatchState, PlayError, PlayerSide};
+use rand::seq::SliceRandom;
+use crate::{GameState, InMatch, CardConfig};
+use crate::card_face::{spawn_card_face, CardTheme};
+use crate::decks::MatchDecks;
+use crate::rng::{GameRng, SeedConfig};
+use crate::turn::{player_can_act, TurnPhase};
+use crate::mana::RejectedPlay;
+use crate::combat::{slot_zone, zone_slot};
+use crate::piles::pile_browser_closed;
+use crate::picking::{sprite_contains, topmost_at};
+use crate::targeting::{start_targeting, PendingAction, Targeting, TargetingSource};
+use crate::startup::VirtualResolution;
+
+// Card back color, shared by face-down cards and the deck
+const CARD_BACK_COLOR: Color = Color::srgb(0.8, 0.75, 0.7);
 
 // Plugin initializer for gameplay systems
 pub fn init_gameplay_systems(app: &mut App) {
-    app.add_systems(OnEnter(GameState::Playing), (setup_gameplay, setup_play_areas).chain())
-        .add_systems(OnExit(GameState::Playing), cleanup_gameplay)
+    app.init_resource::<GameMode>()
+        .init_resource::<MatchRulesConfig>()
+        .add_systems(OnEnter(InMatch), (setup_gameplay, setup_play_areas).chain())
+        .add_systems(OnEnter(TurnPhase::Draw), draw_step_system)
+        .add_systems(OnExit(InMatch), cleanup_gameplay)
         .add_systems(
             Update,
             (
                 hand_layout_system,                // Layout first (position, rotation)
+                opponent_hand_layout_system,
                 card_hover_system,                 // Detect hover
                 card_animation_system,             // Animate scale and z-position last
-                deck_click_system,
-                card_drag_system,                  // Handle card dragging
+                deck_visual_system,
+                card_drag_system                   // Handle card dragging
+                    .run_if(player_can_act.and(pile_browser_closed).and(not(resource_exists::<Targeting>))),
             )
             .run_if(in_state(GameState::Playing)),
+        )
+        // Runs after the systems that act on the rules, so their results show the same frame
+        .add_systems(
+            Update,
+            sync_cards_system
+                .after(card_drag_system)
+                .before(hand_layout_system)
+                .run_if(in_state(GameState::Playing)),
         );
 }
 
@@ -22,10 +52,11 @@ pub fn init_gameplay_systems(app: &mut App) {
 #[derive(Component)]
 pub struct GameEntity;
 
-// Card component that holds the card's data
+// Card component: the match card this entity shows, plus its on-screen state
 #[derive(Component)]
 pub struct Card {
-    pub data: CardData,
+    pub id: CardId,
+    pub data: Arc<CardData>,
     pub is_hovered: bool,
     pub target_scale: f32,
     pub base_size: Vec2,
@@ -33,9 +64,10 @@ pub struct Card {
 }
 
 impl Card {
-    pub fn new(data: CardData, base_size: Vec2) -> Self {
+    pub fn new(card: &CardInstance, base_size: Vec2) -> Self {
         Self {
-            data,
+            id: card.id,
+            data: card.data.clone(),
             is_hovered: false,
             target_scale: 1.0,
             base_size,
@@ -54,15 +86,28 @@ pub struct InHand {
     pub hand_index: usize,
 }
 
+// Component to mark face-down cards in the opponent's hand
+#[derive(Component)]
+pub struct OpponentHandCard;
+
 // Component for cards being dragged
 #[derive(Component)]
 pub struct Dragging {
     pub offset: Vec2,  // Offset from card center to mouse position
     pub original_zone: CardZone,
+    pub origin: Vec2,  // Card center when the drag started
+}
+
+impl Dragging {
+    // Whether the card at `position` has been pulled away from where the drag started, as
+    // opposed to just clicked
+    pub fn moved(&self, position: Vec2, card_size: Vec2) -> bool {
+        position.distance(self.origin) > card_size.x * 0.1
+    }
 }
 
 // Component to mark cards in various zones
-#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum CardZone {
     PlayerHand,
     PlayerPlayArea { slot: usize },
@@ -74,22 +119,38 @@ pub enum CardZone {
     TopRight,
 }
 
-// Resource to configure play area layout
-#[derive(Resource, Clone)]
+// Resource to configure play area layout. Both players' play areas share it, so every lane
+// faces the same lane across the board.
+#[derive(Resource, Clone, Debug, PartialEq, Eq)]
 pub struct PlayAreaConfig {
-    pub player_rows: usize,
-    pub player_slots_per_row: usize,
-    pub opponent_rows: usize,
-    pub opponent_slots_per_row: usize,
+    // Front row first
+    pub rows: usize,
+    // One slot per lane
+    pub slots_per_row: usize,
 }
 
-impl Default for PlayAreaConfig {
-    fn default() -> Self {
-        Self {
-            player_rows: 1,
-            player_slots_per_row: 5,
-            opponent_rows: 1,
-            opponent_slots_per_row: 5,
+// Board shapes a match can be played on, picked in the options menu
+#[derive(Resource, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum GameMode {
+    // One row of five lanes
+    #[default]
+    Standard,
+    // A front line and a back line of four lanes each
+    Battleline,
+}
+
+impl GameMode {
+    pub fn label(&self) -> &'static str {
+        match self {
+            GameMode::Standard => "STANDARD",
+            GameMode::Battleline => "BATTLELINE",
+        }
+    }
+
+    pub fn play_area(&self) -> PlayAreaConfig {
+        match self {
+            GameMode::Standard => PlayAreaConfig { rows: 1, slots_per_row: 5 },
+            GameMode::Battleline => PlayAreaConfig { rows: 2, slots_per_row: 4 },
         }
     }
 }
@@ -109,86 +170,21 @@ pub struct Deck;
 #[derive(Component)]
 pub struct DeckEmpty;
 
-// Resource to track remaining cards in deck
-#[derive(Resource)]
-pub struct DeckCards {
-    pub cards: Vec<CardData>,
-}
-
-// Central gameplay state - source of truth for all game data
-#[derive(Resource, Default)]
-pub struct GameplayState {
-    pub deck: Vec<CardData>,
-    pub player_hand: Vec<Entity>,
-    pub player_play_area: [Option<Entity>; 5],  // 5 slots, each may contain a card entity
-    pub opponent_play_area: [Option<Entity>; 5],
-    pub opponent_hand: Vec<Entity>,
-}
+// Central gameplay state - source of truth for all game data. The rules live in
+// cardigan-core; the cards on screen follow this state (see sync_cards_system).
+#[derive(Resource, Deref, DerefMut)]
+pub struct GameplayState(pub MatchState);
 
-impl GameplayState {
-    pub fn new() -> Self {
-        // Initialize deck with 10 cards
-        let mut deck = Vec::new();
-        for i in 1..=10 {
-            deck.push(CardData::new(format!("Card {}", i)));
-        }
-
-        Self {
-            deck,
-            player_hand: Vec::new(),
-            player_play_area: [None; 5],
-            opponent_play_area: [None; 5],
-            opponent_hand: Vec::new(),
-        }
-    }
-
-    pub fn draw_card(&mut self) -> Option<CardData> {
-        self.deck.pop()
-    }
-
-    pub fn add_to_hand(&mut self, entity: Entity) {
-        self.player_hand.push(entity);
-    }
-
-    pub fn remove_from_hand(&mut self, entity: Entity) {
-        self.player_hand.retain(|&e| e != entity);
-    }
-
-    pub fn play_card_to_slot(&mut self, entity: Entity, slot: usize) -> bool {
-        if slot < 5 && self.player_play_area[slot].is_none() {
-            self.remove_from_hand(entity);
-            self.player_play_area[slot] = Some(entity);
-            true
-        } else {
-            false
-        }
-    }
-
-    pub fn is_slot_occupied(&self, slot: usize) -> bool {
-        slot < 5 && self.player_play_area[slot].is_some()
-    }
-}
-
-// Resource to track window dimensions for anchoring
-#[derive(Resource, Clone)]
-pub struct WindowDimensions {
-    pub width: f32,
-    pub height: f32,
-}
-
-impl Default for WindowDimensions {
-    fn default() -> Self {
-        Self {
-            width: 1280.0,
-            height: 720.0,
-        }
-    }
-}
+// Rules the next match is played with; optional ones are toggled in the options menu
+#[derive(Resource, Default, Deref, DerefMut)]
+pub struct MatchRulesConfig(pub MatchRules);
 
 // Component to mark entities that should be anchored to window edges
 #[derive(Component)]
 pub enum AnchorPosition {
+    #[expect(dead_code)]
     BottomCenter { offset_y: f32 },
+    #[expect(dead_code)]
     TopCenter { offset_y: f32 },
     TopRight { offset_x: f32, offset_y: f32 },
     TopLeft { offset_x: f32, offset_y: f32 },
@@ -196,6 +192,30 @@ pub enum AnchorPosition {
     BottomLeft { offset_x: f32, offset_y: f32 },
 }
 
+impl AnchorPosition {
+    // World position of the anchor: the window edge or corner plus its offset
+    pub fn resolve(&self, resolution: &VirtualResolution) -> Vec2 {
+        let half_width = resolution.width / 2.0;
+        let half_height = resolution.height / 2.0;
+        match *self {
+            AnchorPosition::BottomCenter { offset_y } => Vec2::new(0.0, -half_height + offset_y),
+            AnchorPosition::TopCenter { offset_y } => Vec2::new(0.0, half_height + offset_y),
+            AnchorPosition::TopRight { offset_x, offset_y } => {
+                Vec2::new(half_width + offset_x, half_height + offset_y)
+            }
+            AnchorPosition::TopLeft { offset_x, offset_y } => {
+                Vec2::new(-half_width + offset_x, half_height + offset_y)
+            }
+            AnchorPosition::BottomRight { offset_x, offset_y } => {
+                Vec2::new(half_width + offset_x, -half_height + offset_y)
+            }
+            AnchorPosition::BottomLeft { offset_x, offset_y } => {
+                Vec2::new(-half_width + offset_x, -half_height + offset_y)
+            }
+        }
+    }
+}
+
 // Layout zones helper - calculates positions for different screen areas
 pub struct LayoutZones {
     pub card_size: Vec2,
@@ -204,8 +224,8 @@ pub struct LayoutZones {
 }
 
 impl LayoutZones {
-    pub fn new(window_dims: &WindowDimensions) -> Self {
-        let card_height = window_dims.height * 0.40;
+    pub fn new(resolution: &VirtualResolution) -> Self {
+        let card_height = resolution.height * 0.40;
         let card_width = card_height * (2.0 / 3.0);
         let card_size = Vec2::new(card_width, card_height);
 
@@ -217,48 +237,94 @@ impl LayoutZones {
     }
 
     /// Get Y position for player's hand (bottom of screen)
-    pub fn player_hand_y(&self, window_dims: &WindowDimensions) -> f32 {
+    pub fn player_hand_y(&self, resolution: &VirtualResolution) -> f32 {
         let card_half_height = self.card_height / 2.0;
-        let bottom_margin = window_dims.height * 0.025;
+        let bottom_margin = resolution.height * 0.025;
         // Bottom of screen is -height/2, add margin and half card height to center card
-        -(window_dims.height / 2.0) + bottom_margin + card_half_height
+        -(resolution.height / 2.0) + bottom_margin + card_half_height
     }
 
-    /// Get Y position for player's play area (above hand, still lower half)
-    pub fn player_play_area_y(&self, window_dims: &WindowDimensions) -> f32 {
-        let hand_y = self.player_hand_y(window_dims);
-        // Player's play area is above the hand
-        // Add full card height plus gap (still below center in most cases)
-        hand_y + self.card_height + (window_dims.height * 0.08)
+    /// Size of a play area slot: a full card for a single row; extra rows share that height
+    pub fn slot_size(&self, config: &PlayAreaConfig) -> Vec2 {
+        self.card_size / config.rows.max(1) as f32
     }
 
-    /// Get Y position for opponent's play area (upper half, below opponent's hand)
-    pub fn opponent_play_area_y(&self, window_dims: &WindowDimensions) -> f32 {
-        let card_half_height = self.card_height / 2.0;
-        let top_margin = window_dims.height * 0.025;
-        let opponent_hand_y = (window_dims.height / 2.0) - top_margin - card_half_height;
-        // Opponent's play area is below their hand
-        opponent_hand_y - self.card_height - (window_dims.height * 0.08)
+    /// Centers of one side's play area slots in slot order: row by row, front row first, left
+    /// to right. Each side's rows stack outward from the middle of the screen, the player's
+    /// in the lower half.
+    pub fn play_area_grid(&self, side: PlayerSide, config: &PlayAreaConfig) -> Vec<Vec2> {
+        let slot_size = self.slot_size(config);
+        let row_gap = self.card_height * 0.05;
+        let direction = match side {
+            PlayerSide::Player => -1.0,
+            PlayerSide::Opponent => 1.0,
+        };
+
+        (0..config.rows)
+            .flat_map(|row| {
+                let distance = row_gap / 2.0 + slot_size.y / 2.0 + row as f32 * (slot_size.y + row_gap);
+                self.calculate_slot_positions(config.slots_per_row, slot_size.x, direction * distance)
+            })
+            .collect()
     }
 
     /// Get Y position for opponent's hand (top of screen)
-    pub fn opponent_hand_y(&self, window_dims: &WindowDimensions) -> f32 {
-        -self.player_hand_y(window_dims)
+    pub fn opponent_hand_y(&self, resolution: &VirtualResolution) -> f32 {
+        -self.player_hand_y(resolution)
+    }
+
+    /// Offset from the hand's center and rotation for the card at `index` in a splayed hand.
+    /// Cards rise towards the middle of the arc and fan outward; a hovered card pushes its
+    /// neighbours apart.
+    pub fn hand_arc(&self, index: usize, hand_count: usize, hovered_index: Option<usize>) -> (Vec2, f32) {
+        // Spacing based on card width for proportional layout
+        let card_spacing = self.card_width * 0.4;  // 40% of card width between cards
+        let arc_height = self.card_height * 0.1;   // 10% of card height for arc
+        let rotation_per_card = 0.08;              // Rotation in radians per card from center
+        let hover_spread = self.card_width * 0.3;  // 30% of card width for hover spread
+
+        // Calculate total width and starting position
+        let total_width = hand_count.saturating_sub(1) as f32 * card_spacing;
+        let start_x = -total_width / 2.0;
+
+        // Calculate base position along the arc
+        let mut x_offset = index as f32 * card_spacing;
+
+        // Apply spreading effect when a card is hovered
+        if let Some(hovered_idx) = hovered_index {
+            if index > hovered_idx {
+                // Cards to the right of hovered card: push right
+                x_offset += hover_spread;
+            } else if index < hovered_idx {
+                // Cards to the left of hovered card: push left
+                x_offset -= hover_spread;
+            }
+        }
+
+        // Calculate arc (parabolic curve)
+        let center_offset = index as f32 - hand_count.saturating_sub(1) as f32 / 2.0;
+        let normalized_offset = center_offset / ((hand_count as f32) / 2.0).max(1.0);
+        let y_offset = arc_height * (1.0 - normalized_offset * normalized_offset);
+
+        // Calculate rotation (cards fan outward)
+        let rotation = -center_offset * rotation_per_card;
+
+        (Vec2::new(start_x + x_offset, y_offset), rotation)
     }
 
     /// Calculate positions for a row of card slots
-    pub fn calculate_slot_positions(&self, num_slots: usize, center_y: f32) -> Vec<Vec2> {
+    pub fn calculate_slot_positions(&self, num_slots: usize, slot_width: f32, center_y: f32) -> Vec<Vec2> {
         if num_slots == 0 {
             return vec![];
         }
 
-        let spacing = self.card_width * 0.2; // 20% of card width between slots
-        let total_width = (num_slots as f32 * self.card_width) + ((num_slots - 1) as f32 * spacing);
+        let spacing = slot_width * 0.2; // 20% of slot width between slots
+        let total_width = (num_slots as f32 * slot_width) + ((num_slots - 1) as f32 * spacing);
         let start_x = -total_width / 2.0;
 
         (0..num_slots)
             .map(|i| {
-                let x = start_x + (i as f32 * (self.card_width + spacing)) + (self.card_width / 2.0);
+                let x = start_x + (i as f32 * (slot_width + spacing)) + (slot_width / 2.0);
                 Vec2::new(x, center_y)
             })
             .collect()
@@ -266,41 +332,49 @@ impl LayoutZones {
 }
 
 // Setup gameplay (spawn deck and initialize hand)
-pub fn setup_gameplay(mut commands: Commands, window_query: Query<&Window>) {
-    // Initialize window dimensions resource
-    let window_dims = if let Some(window) = window_query.iter().next() {
-        let dims = WindowDimensions {
-            width: window.width(),
-            height: window.height(),
-        };
-        commands.insert_resource(dims.clone());
-        dims
-    } else {
-        let dims = WindowDimensions::default();
-        commands.insert_resource(dims.clone());
-        dims
-    };
-
+pub fn setup_gameplay(
+    mut commands: Commands,
+    resolution: Res<VirtualResolution>,
+    match_decks: Res<MatchDecks>,
+    seed_config: Res<SeedConfig>,
+    match_rules: Res<MatchRulesConfig>,
+    game_mode: Res<GameMode>,
+) {
     // Card size: Use viewport height as reference for consistent scaling
     // Card height: 40% of viewport height
     // Card width: 2:3 aspect ratio (width = height * 2/3)
-    let card_height = window_dims.height * 0.40;
+    let card_height = resolution.height * 0.40;
     let card_width = card_height * (2.0 / 3.0);
     let card_size = Vec2::new(card_width, card_height);
 
-    // Initialize gameplay state
-    commands.insert_resource(GameplayState::new());
-
-    // Keep DeckCards for backward compatibility (will migrate fully later)
-    commands.insert_resource(DeckCards { cards: Vec::new() });
+    // Seed this match's RNG; the seed is shown in the pause menu so a game can be replayed
+    let mut rng = GameRng::new(seed_config.next_seed());
+    info!("Starting match with seed {}", rng.seed());
+
+    // Initialize gameplay state with each player's shuffled deck list
+    let mut deck = match_decks.player.clone();
+    let mut opponent_deck = match_decks.opponent.clone();
+    deck.shuffle(&mut rng);
+    opponent_deck.shuffle(&mut rng);
+    // The game mode picks the board's shape, and the rules play on the same grid
+    let play_area = game_mode.play_area();
+    let mut rules = match_rules.0.clone();
+    rules.board_rows = play_area.rows;
+    rules.board_slots = play_area.slots_per_row;
+    commands.insert_resource(play_area);
+
+    let mut match_state = MatchState::new(deck, opponent_deck, rules);
+    match_state.deal_opening_hands();
+    commands.insert_resource(GameplayState(match_state));
+    commands.insert_resource(rng);
 
     // Deck position: Scale with viewport height for consistency
     // Offset from right: 1.5x card width, offset from bottom: 0.6x card height
     let deck_offset = card_width * 0.1;
     let deck_offset_x = -(card_width * 0.5 + deck_offset);
     let deck_offset_y = card_height * 0.5 + deck_offset;
-    let deck_x = (window_dims.width / 2.0) + deck_offset_x;
-    let deck_y = -(window_dims.height / 2.0) + deck_offset_y;
+    let deck_x = (resolution.width / 2.0) + deck_offset_x;
+    let deck_y = -(resolution.height / 2.0) + deck_offset_y;
 
     // Spawn deck visual at bottom-right of screen
     commands.spawn((
@@ -310,7 +384,7 @@ pub fn setup_gameplay(mut commands: Commands, window_query: Query<&Window>) {
             offset_y: deck_offset_y,
         },
         Sprite {
-            color: Color::srgb(0.8, 0.75, 0.7),  // Card back color
+            color: CARD_BACK_COLOR,
             custom_size: Some(card_size),
             ..default()
         },
@@ -348,57 +422,25 @@ pub fn cleanup_gameplay(mut commands: Commands, game_entities: Query<Entity, Wit
     }
 }
 
-// Setup play area card slots
+// Setup play area card slots: a grid for each side, in the shape the game mode picked
 pub fn setup_play_areas(
     mut commands: Commands,
-    window_query: Query<&Window>,
+    resolution: Res<VirtualResolution>,
+    config: Res<PlayAreaConfig>,
 ) {
-    let window_dims = if let Some(window) = window_query.iter().next() {
-        WindowDimensions {
-            width: window.width(),
-            height: window.height(),
+    let layout = LayoutZones::new(&resolution);
+    let slot_size = layout.slot_size(&config);
+
+    for side in [PlayerSide::Player, PlayerSide::Opponent] {
+        for (slot_index, position) in layout.play_area_grid(side, &config).into_iter().enumerate() {
+            spawn_card_slot(
+                &mut commands,
+                slot_zone(side, slot_index),
+                position,
+                slot_size,
+                false,  // Initially unoccupied
+            );
         }
-    } else {
-        WindowDimensions::default()
-    };
-
-    // Initialize play area configuration - 1 row of 5 slots for now
-    let config = PlayAreaConfig {
-        player_rows: 1,
-        player_slots_per_row: 5,
-        opponent_rows: 1,
-        opponent_slots_per_row: 5,
-    };
-    commands.insert_resource(config.clone());
-
-    let layout = LayoutZones::new(&window_dims);
-
-    // Spawn player play area slots
-    let player_y = layout.player_play_area_y(&window_dims);
-    let player_positions = layout.calculate_slot_positions(config.player_slots_per_row, player_y);
-
-    for (slot_index, position) in player_positions.iter().enumerate() {
-        spawn_card_slot(
-            &mut commands,
-            CardZone::PlayerPlayArea { slot: slot_index },
-            *position,
-            layout.card_size,
-            false,  // Initially unoccupied
-        );
-    }
-
-    // Spawn opponent play area slots
-    let opponent_y = layout.opponent_play_area_y(&window_dims);
-    let opponent_positions = layout.calculate_slot_positions(config.opponent_slots_per_row, opponent_y);
-
-    for (slot_index, position) in opponent_positions.iter().enumerate() {
-        spawn_card_slot(
-            &mut commands,
-            CardZone::OpponentPlayArea { slot: slot_index },
-            *position,
-            layout.card_size,
-            false,  // Initially unoccupied
-        );
     }
 }
 
@@ -423,129 +465,112 @@ fn spawn_card_slot(
         Transform::from_xyz(position.x, position.y, -10.0),
         GameEntity,
     ))
-    .with_children(|parent| {
-        // Dashed border effect using multiple rectangles
-        let border_width = 4.0;
-        let dash_length = 20.0;
-        let gap_length = 10.0;
-
-        let border_color = Color::srgba(0.4, 0.4, 0.5, 0.4);
-
-        // Top border dashes
-        let mut x = -card_size.x / 2.0 + dash_length / 2.0;
-        let y_top = card_size.y / 2.0;
-        while x + dash_length / 2.0 <= card_size.x / 2.0 {
-            let actual_dash_length = (dash_length).min(card_size.x / 2.0 - x + dash_length / 2.0);
-            parent.spawn((
-                Sprite {
-                    color: border_color,
-                    custom_size: Some(Vec2::new(actual_dash_length, border_width)),
-                    ..default()
-                },
-                Transform::from_xyz(x, y_top, 0.1),
-            ));
-            x += dash_length + gap_length;
-        }
+    .with_children(|parent| spawn_slot_border(parent, card_size));
+}
 
-        // Bottom border dashes
-        let mut x = -card_size.x / 2.0 + dash_length / 2.0;
-        let y_bottom = -card_size.y / 2.0;
-        while x + dash_length / 2.0 <= card_size.x / 2.0 {
-            let actual_dash_length = (dash_length).min(card_size.x / 2.0 - x + dash_length / 2.0);
-            parent.spawn((
-                Sprite {
-                    color: border_color,
-                    custom_size: Some(Vec2::new(actual_dash_length, border_width)),
-                    ..default()
-                },
-                Transform::from_xyz(x, y_bottom, 0.1),
-            ));
-            x += dash_length + gap_length;
-        }
+// Spawn the dashed outline of a card slot as children of the slot entity
+fn spawn_slot_border(parent: &mut ChildSpawnerCommands, card_size: Vec2) {
+    // Dashed border effect using multiple rectangles
+    let border_width = 4.0;
+    let dash_length = 20.0;
+    let gap_length = 10.0;
 
-        // Left border dashes
-        let x_left = -card_size.x / 2.0;
-        let mut y = -card_size.y / 2.0 + dash_length / 2.0;
-        while y + dash_length / 2.0 <= card_size.y / 2.0 {
-            let actual_dash_length = (dash_length).min(card_size.y / 2.0 - y + dash_length / 2.0);
-            parent.spawn((
-                Sprite {
-                    color: border_color,
-                    custom_size: Some(Vec2::new(border_width, actual_dash_length)),
-                    ..default()
-                },
-                Transform::from_xyz(x_left, y, 0.1),
-            ));
-            y += dash_length + gap_length;
-        }
+    let border_color = Color::srgba(0.4, 0.4, 0.5, 0.4);
 
-        // Right border dashes
-        let x_right = card_size.x / 2.0;
-        let mut y = -card_size.y / 2.0 + dash_length / 2.0;
-        while y + dash_length / 2.0 <= card_size.y / 2.0 {
-            let actual_dash_length = (dash_length).min(card_size.y / 2.0 - y + dash_length / 2.0);
-            parent.spawn((
-                Sprite {
-                    color: border_color,
-                    custom_size: Some(Vec2::new(border_width, actual_dash_length)),
-                    ..default()
-                },
-                Transform::from_xyz(x_right, y, 0.1),
-            ));
-            y += dash_length + gap_length;
-        }
-    });
+    // Top border dashes
+    let mut x = -card_size.x / 2.0 + dash_length / 2.0;
+    let y_top = card_size.y / 2.0;
+    while x + dash_length / 2.0 <= card_size.x / 2.0 {
+        let actual_dash_length = (dash_length).min(card_size.x / 2.0 - x + dash_length / 2.0);
+        parent.spawn((
+            Sprite {
+                color: border_color,
+                custom_size: Some(Vec2::new(actual_dash_length, border_width)),
+                ..default()
+            },
+            Transform::from_xyz(x, y_top, 0.1),
+        ));
+        x += dash_length + gap_length;
+    }
+
+    // Bottom border dashes
+    let mut x = -card_size.x / 2.0 + dash_length / 2.0;
+    let y_bottom = -card_size.y / 2.0;
+    while x + dash_length / 2.0 <= card_size.x / 2.0 {
+        let actual_dash_length = (dash_length).min(card_size.x / 2.0 - x + dash_length / 2.0);
+        parent.spawn((
+            Sprite {
+                color: border_color,
+                custom_size: Some(Vec2::new(actual_dash_length, border_width)),
+                ..default()
+            },
+            Transform::from_xyz(x, y_bottom, 0.1),
+        ));
+        x += dash_length + gap_length;
+    }
+
+    // Left border dashes
+    let x_left = -card_size.x / 2.0;
+    let mut y = -card_size.y / 2.0 + dash_length / 2.0;
+    while y + dash_length / 2.0 <= card_size.y / 2.0 {
+        let actual_dash_length = (dash_length).min(card_size.y / 2.0 - y + dash_length / 2.0);
+        parent.spawn((
+            Sprite {
+                color: border_color,
+                custom_size: Some(Vec2::new(border_width, actual_dash_length)),
+                ..default()
+            },
+            Transform::from_xyz(x_left, y, 0.1),
+        ));
+        y += dash_length + gap_length;
+    }
+
+    // Right border dashes
+    let x_right = card_size.x / 2.0;
+    let mut y = -card_size.y / 2.0 + dash_length / 2.0;
+    while y + dash_length / 2.0 <= card_size.y / 2.0 {
+        let actual_dash_length = (dash_length).min(card_size.y / 2.0 - y + dash_length / 2.0);
+        parent.spawn((
+            Sprite {
+                color: border_color,
+                custom_size: Some(Vec2::new(border_width, actual_dash_length)),
+                ..default()
+            },
+            Transform::from_xyz(x_right, y, 0.1),
+        ));
+        y += dash_length + gap_length;
+    }
 }
 
 // System to detect card hover (using mouse position and sprite bounds)
 // Only allows hovering the topmost card under the cursor
 pub fn card_hover_system(
-    mut card_query: Query<(Entity, &mut Card, &Transform, &Sprite, &Children, Option<&Dragging>)>,
+    mut card_query: Query<
+        (Entity, &mut Card, &Transform, &Sprite, &Children, Option<&Dragging>),
+        Without<OpponentHandCard>,
+    >,
     mut sprite_query: Query<&mut Sprite, Without<Card>>,
     window_query: Query<&Window>,
-    camera_query: Query<(&Camera, &GlobalTransform)>,
+    resolution: Res<VirtualResolution>,
     card_config: Res<CardConfig>,
 ) {
     let Some(window) = window_query.iter().next() else {
         return;
     };
 
-    let Some((camera, camera_transform)) = camera_query.iter().next() else {
-        return;
-    };
-
     // Get cursor position in world space
-    let cursor_world_pos: Option<Vec2> = window.cursor_position()
-        .and_then(|cursor| camera.viewport_to_world_2d(camera_transform, cursor).ok());
-
-    // Find the topmost card under the cursor
-    let mut topmost_card: Option<(Entity, f32)> = None;
-
-    if let Some(cursor_pos) = cursor_world_pos {
-        for (entity, _card, transform, sprite, _children, dragging) in card_query.iter() {
-            // Skip cards that are being dragged
-            if dragging.is_some() {
-                continue;
-            }
-
-            if let Some(size) = sprite.custom_size {
-                let card_pos = transform.translation.truncate();
-                let half_size = size * transform.scale.truncate() / 2.0;
-
-                let is_under_cursor = cursor_pos.x >= card_pos.x - half_size.x &&
-                    cursor_pos.x <= card_pos.x + half_size.x &&
-                    cursor_pos.y >= card_pos.y - half_size.y &&
-                    cursor_pos.y <= card_pos.y + half_size.y;
-
-                if is_under_cursor {
-                    let z = transform.translation.z;
-                    if topmost_card.is_none() || z > topmost_card.unwrap().1 {
-                        topmost_card = Some((entity, z));
-                    }
-                }
-            }
-        }
-    }
+    let cursor_world_pos: Option<Vec2> = resolution.cursor_position(window);
+
+    // Find the topmost card under the cursor, skipping cards that are being dragged
+    let topmost_card = cursor_world_pos.and_then(|cursor_pos| {
+        topmost_at(
+            cursor_pos,
+            card_query
+                .iter()
+                .filter(|(.., dragging)| dragging.is_none())
+                .map(|(entity, _card, transform, sprite, ..)| (entity, transform, sprite)),
+        )
+    });
 
     // Update hover state for all cards
     for (entity, mut card, _transform, _sprite, children, dragging) in card_query.iter_mut() {
@@ -553,7 +578,7 @@ pub fn card_hover_system(
         if dragging.is_some() {
             continue;
         }
-        let should_hover = topmost_card.map(|(e, _)| e == entity).unwrap_or(false);
+        let should_hover = topmost_card == Some(entity);
 
         if should_hover != card.is_hovered {
             card.is_hovered = should_hover;
@@ -564,84 +589,72 @@ pub fn card_hover_system(
             };
 
             // Update border color (first child is the border)
-            if let Some(&border_entity) = children.get(0) {
-                if let Ok(mut border_sprite) = sprite_query.get_mut(border_entity) {
-                    border_sprite.color = if should_hover {
-                        Color::srgb(0.4, 0.6, 0.9)  // Blue highlight
-                    } else {
-                        Color::srgb(0.3, 0.3, 0.4)  // Normal border
-                    };
-                }
+            if let Some(&border_entity) = children.first()
+                && let Ok(mut border_sprite) = sprite_query.get_mut(border_entity)
+            {
+                border_sprite.color = if should_hover {
+                    Color::srgb(0.4, 0.6, 0.9)  // Blue highlight
+                } else {
+                    Color::srgb(0.3, 0.3, 0.4)  // Normal border
+                };
             }
         }
     }
 }
 
-// System to handle card dragging and dropping
+// The id of the hand card under the cursor. The hover system has already found the topmost
+// card there, so systems that pick hand cards by clicking only have to look it up.
+pub fn hovered_hand_card(card_query: &Query<&Card, With<InHand>>) -> Option<CardId> {
+    card_query.iter().find(|card| card.is_hovered).map(|card| card.id)
+}
+
+// System to handle card dragging and dropping. Hand cards are played by dropping them on a
+// slot; the player's creatures can be moved to another empty slot while the stack is empty.
 pub fn card_drag_system(
     mut commands: Commands,
-    mut card_query: Query<(Entity, &mut Card, &Transform, &Sprite, Option<&Dragging>, Option<&InHand>)>,
-    mut slot_query: Query<(Entity, &mut CardSlot, &Transform, &Sprite)>,
+    mut card_query: Query<(Entity, &mut Card, &CardZone, &Transform, &Sprite, Option<&Dragging>, Option<&InHand>)>,
+    slot_query: Query<(&CardSlot, &Transform, &Sprite)>,
     mut gameplay_state: ResMut<GameplayState>,
     mouse_button: Res<ButtonInput<MouseButton>>,
     window_query: Query<&Window>,
-    camera_query: Query<(&Camera, &GlobalTransform)>,
+    resolution: Res<VirtualResolution>,
 ) {
     let Some(window) = window_query.iter().next() else {
         return;
     };
 
-    let Some((camera, camera_transform)) = camera_query.iter().next() else {
-        return;
-    };
-
-    let cursor_world_pos = window.cursor_position()
-        .and_then(|cursor| camera.viewport_to_world_2d(camera_transform, cursor).ok());
+    let cursor_world_pos = resolution.cursor_position(window);
 
     // Start dragging
-    if mouse_button.just_pressed(MouseButton::Left) {
-        if let Some(cursor_pos) = cursor_world_pos {
-            // Find the topmost card under cursor that's in hand
-            let mut topmost_card: Option<(Entity, f32, Vec2)> = None;
-
-            for (entity, _card, transform, sprite, dragging, in_hand) in card_query.iter() {
-                // Only allow dragging cards in hand
-                if dragging.is_some() || in_hand.is_none() {
-                    continue;
-                }
-
-                if let Some(size) = sprite.custom_size {
-                    let card_pos = transform.translation.truncate();
-                    let half_size = size * transform.scale.truncate() / 2.0;
-
-                    let is_under_cursor = cursor_pos.x >= card_pos.x - half_size.x &&
-                        cursor_pos.x <= card_pos.x + half_size.x &&
-                        cursor_pos.y >= card_pos.y - half_size.y &&
-                        cursor_pos.y <= card_pos.y + half_size.y;
-
-                    if is_under_cursor {
-                        let z = transform.translation.z;
-                        if topmost_card.is_none() || z > topmost_card.unwrap().1 {
-                            topmost_card = Some((entity, z, card_pos));
-                        }
-                    }
-                }
-            }
+    if mouse_button.just_pressed(MouseButton::Left)
+        && let Some(cursor_pos) = cursor_world_pos
+    {
+        // Find the topmost card under cursor that's in hand or on the player's board
+        let draggable = card_query.iter().filter(|(_, _, zone, _, _, dragging, in_hand)| {
+            let on_board = matches!(zone_slot(zone), Some((PlayerSide::Player, _)));
+            dragging.is_none() && (in_hand.is_some() || on_board && gameplay_state.stack.is_empty())
+        });
+        let topmost_card = topmost_at(
+            cursor_pos,
+            draggable.map(|(entity, _card, zone, transform, sprite, ..)| {
+                ((entity, transform.translation.truncate(), *zone), transform, sprite)
+            }),
+        );
 
-            // Start dragging the topmost card
-            if let Some((entity, _, card_pos)) = topmost_card {
-                let offset = cursor_pos - card_pos;
-                commands.entity(entity).insert(Dragging {
-                    offset,
-                    original_zone: CardZone::PlayerHand,
-                });
-            }
+        // Start dragging the topmost card
+        if let Some((entity, card_pos, zone)) = topmost_card {
+            let offset = cursor_pos - card_pos;
+            commands.entity(entity).insert(Dragging {
+                offset,
+                original_zone: zone,
+                origin: card_pos,
+            });
         }
     }
 
     // Update dragging cards position
     if let Some(cursor_pos) = cursor_world_pos {
-        for (entity, mut card, transform, _sprite, dragging, _in_hand) in card_query.iter_mut() {
+        for (entity, mut card, _zone, transform, _sprite, dragging, _in_hand) in card_query.iter_mut() {
             if let Some(drag) = dragging {
                 let new_pos = cursor_pos - drag.offset;
                 card.target_position = new_pos;
@@ -657,108 +670,110 @@ pub fn card_drag_system(
 
     // Stop dragging and check for drop
     if mouse_button.just_released(MouseButton::Left) {
-        for (entity, mut card, transform, _sprite, dragging, in_hand) in card_query.iter_mut() {
-            if let Some(_drag) = dragging {
-                let card_pos = transform.translation.truncate();
-
-                // Check if dropped on a valid slot
-                let mut dropped_on_slot = false;
-                let mut target_slot_entity: Option<Entity> = None;
-                let mut target_slot_pos: Option<Vec2> = None;
-                let mut target_zone: Option<CardZone> = None;
-
-                for (slot_entity, slot, slot_transform, slot_sprite) in slot_query.iter() {
-                    // Only check player play area slots
-                    let slot_index = if let CardZone::PlayerPlayArea { slot } = slot.zone {
-                        slot
-                    } else {
-                        continue;
-                    };
-
-                    // Check if slot is occupied in gameplay state
-                    if gameplay_state.is_slot_occupied(slot_index) {
-                        continue;
-                    }
+        for (entity, mut card, _zone, transform, _sprite, dragging, _in_hand) in card_query.iter_mut() {
+            let Some(drag) = dragging else {
+                continue;
+            };
+            let card_pos = transform.translation.truncate();
+            let is_creature = card.data.stats.is_some();
+
+            // Find the player slot the card was dropped on, if any. Creatures need an empty
+            // one; spells can be dropped on any.
+            let target_slot = slot_query.iter().find_map(|(slot, slot_transform, slot_sprite)| {
+                // Only check player play area slots
+                let CardZone::PlayerPlayArea { slot: slot_index } = slot.zone else {
+                    return None;
+                };
 
-                    if let Some(slot_size) = slot_sprite.custom_size {
-                        let slot_pos = slot_transform.translation.truncate();
-                        let half_size = slot_size / 2.0;
-
-                        let is_over_slot = card_pos.x >= slot_pos.x - half_size.x &&
-                            card_pos.x <= slot_pos.x + half_size.x &&
-                            card_pos.y >= slot_pos.y - half_size.y &&
-                            card_pos.y <= slot_pos.y + half_size.y;
-
-                        if is_over_slot {
-                            // Store info for dropping the card
-                            target_slot_entity = Some(slot_entity);
-                            target_slot_pos = Some(slot_pos);
-                            target_zone = Some(slot.zone);
-                            dropped_on_slot = true;
-                            break;
-                        }
-                    }
+                // Check if slot is occupied in gameplay state
+                if is_creature && gameplay_state.player.board.get(slot_index).is_none_or(Option::is_some) {
+                    return None;
                 }
 
-                // Update card and slot if dropped on valid slot
-                if dropped_on_slot {
-                    if let (Some(slot_entity), Some(slot_pos), Some(zone)) =
-                        (target_slot_entity, target_slot_pos, target_zone) {
-
-                        // Extract slot number from zone
-                        if let CardZone::PlayerPlayArea { slot: slot_index } = zone {
-                            // Remove from hand in gameplay state
-                            gameplay_state.remove_from_hand(entity);
-
-                            // Play card to slot in gameplay state
-                            gameplay_state.play_card_to_slot(entity, slot_index);
-
-                            // Update card
-                            card.target_position = slot_pos;
-
-                            // Remove from hand component
-                            if in_hand.is_some() {
-                                commands.entity(entity).remove::<InHand>();
-                            }
-
-                            // Reset rotation to 0 for played cards
-                            commands.entity(entity).insert(Transform {
-                                rotation: Quat::IDENTITY,
-                                ..Default::default()
-                            });
-
-                            // Add zone marker
-                            commands.entity(entity).insert(zone);
-
-                            // Mark slot as occupied (for visual consistency)
-                            if let Ok((_, mut slot, _, _)) = slot_query.get_mut(slot_entity) {
-                                slot.occupied = true;
-                            }
-                        }
+                let slot_pos = slot_transform.translation.truncate();
+                sprite_contains(slot_transform, slot_sprite, card_pos).then_some((slot_index, slot_pos))
+            });
+
+            // A creature dropped on another empty slot moves there; sync_cards_system follows
+            // the board. Anywhere else it goes back to its own slot.
+            if let CardZone::PlayerPlayArea { slot: from } = drag.original_zone {
+                let moved = match target_slot.map(|(to, _)| gameplay_state.move_unit(from, to)) {
+                    Some(Ok(())) => true,
+                    // A creature that isn't ready flashes to show why it stays put
+                    Some(Err(PlayError::NotReady)) => {
+                        commands.entity(entity).insert(RejectedPlay::new());
+                        false
+                    }
+                    Some(Err(error)) => {
+                        warn!("Couldn't move {}: {}", card.data.name, error);
+                        false
                     }
+                    None => false,
+                };
+                if !moved
+                    && let Some((_, slot_transform, _)) =
+                        slot_query.iter().find(|(slot, _, _)| slot.zone == drag.original_zone)
+                {
+                    card.target_position = slot_transform.translation.truncate();
                 }
+                commands.entity(entity).remove::<Dragging>();
+                continue;
+            }
 
-                // If not dropped on a valid slot, return to hand
-                if !dropped_on_slot {
-                    // Card will be repositioned by hand_layout_system
+            // Play the card, first asking for a target if it needs one; sync_cards_system moves
+            // it into its slot (or, for a spell, off the board). If it isn't played,
+            // hand_layout_system snaps it back to the hand.
+            if let Some((slot_index, slot_pos)) = target_slot
+                && let Some(hand_index) =
+                    gameplay_state.player.hand.iter().position(|held| held.id == card.id)
+            {
+                let requirement = card.data.play_target();
+                let legal = requirement
+                    .map(|requirement| gameplay_state.legal_targets(PlayerSide::Player, requirement))
+                    .unwrap_or_default();
+                let result = gameplay_state.check_play(hand_index, slot_index).and_then(|()| {
+                    if legal.is_empty() {
+                        // A creature whose target is missing is still played; a spell isn't
+                        return match requirement {
+                            Some(_) if !is_creature => Err(PlayError::TargetRequired),
+                            _ => gameplay_state.play(hand_index, slot_index, None),
+                        };
+                    }
+                    // Creatures wait in their slot while the target is chosen
+                    if is_creature {
+                        card.target_position = slot_pos;
+                    }
+                    let action = PendingAction::Play { card: card.id, slot: slot_index };
+                    start_targeting(&mut commands, action, entity, legal);
+                    Ok(())
+                });
+                match result {
+                    Ok(()) => {}
+                    // The player couldn't pay, the spell has nothing to target or a creature
+                    // was played in response, so the card flashes to show why
+                    Err(PlayError::NotEnoughMana { .. } | PlayError::TargetRequired | PlayError::StackNotEmpty) => {
+                        commands.entity(entity).insert(RejectedPlay::new());
+                    }
+                    Err(error) => warn!("Couldn't play {}: {}", card.data.name, error),
                 }
-
-                // Remove dragging component
-                commands.entity(entity).remove::<Dragging>();
             }
+
+            // Remove dragging component
+            commands.entity(entity).remove::<Dragging>();
         }
     }
 }
+
 // System to animate card scale, position, and z-position
 pub fn card_animation_system(
-    mut card_query: Query<(&Card, &mut Transform, Option<&InHand>)>,
+    mut card_query: Query<(&Card, &mut Transform, Option<&InHand>, Has<OpponentHandCard>)>,
     card_config: Res<CardConfig>,
     time: Res<Time>,
     hand_query: Query<&InHand>,
 ) {
     let hand_count = hand_query.iter().count();
 
-    for (card, mut transform, in_hand) in card_query.iter_mut() {
+    for (card, mut transform, in_hand, in_opponent_hand) in card_query.iter_mut() {
         // Smoothly interpolate to target scale
         let current_scale = transform.scale.x;
         let scale_diff = card.target_scale - current_scale;
@@ -799,271 +814,381 @@ pub fn card_animation_system(
                 // Set z-position instantly (no smooth interpolation)
                 transform.translation.z = target_z;
             }
-        } else {
+        } else if !in_opponent_hand {
             // Cards not in hand should be at z=0 (play area, etc.)
+            // The opponent's hand is layered by opponent_hand_layout_system
             transform.translation.z = 0.0;
         }
     }
 }
 
-// System to handle clicking on the deck to draw cards
-pub fn deck_click_system(
+// Once the player's deck runs out, however that happens, replace it with an empty placeholder
+pub fn deck_visual_system(
     mut commands: Commands,
-    deck_query: Query<(Entity, &Transform, &Sprite, &Children), With<Deck>>,
-    mut gameplay_state: ResMut<GameplayState>,
-    hand_query: Query<&InHand>,
-    mouse_button: Res<ButtonInput<MouseButton>>,
-    window_query: Query<&Window>,
-    camera_query: Query<(&Camera, &GlobalTransform)>,
+    gameplay_state: Res<GameplayState>,
+    deck_query: Query<(Entity, &Transform, &Sprite), With<Deck>>,
 ) {
-    if !mouse_button.just_pressed(MouseButton::Left) {
+    if !gameplay_state.is_changed() || !gameplay_state.player.deck.is_empty() {
         return;
     }
-
-    let Some(window) = window_query.iter().next() else {
+    let Ok((deck_entity, deck_transform, deck_sprite)) = deck_query.single() else {
         return;
     };
-
-    let Some((camera, camera_transform)) = camera_query.iter().next() else {
+    let Some(card_size) = deck_sprite.custom_size else {
         return;
     };
 
-    // Get cursor position in world space
-    let Some(cursor_world_pos) = window.cursor_position()
-        .and_then(|cursor| camera.viewport_to_world_2d(camera_transform, cursor).ok()) else {
+    // Despawning the deck takes its children with it
+    commands.entity(deck_entity).despawn();
+
+    let deck_pos = deck_transform.translation;
+
+    // Deck position: Scale with card size
+    let deck_offset = card_size.x * 0.1;
+    let deck_offset_x = -(card_size.x * 0.5 + deck_offset);
+    let deck_offset_y = card_size.y * 0.5 + deck_offset;
+
+    // Spawn empty deck placeholder
+    commands.spawn((
+        DeckEmpty,
+        AnchorPosition::BottomRight {
+            offset_x: deck_offset_x,
+            offset_y: deck_offset_y,
+        },
+        Sprite {
+            color: Color::NONE,  // Transparent background
+            custom_size: Some(card_size),
+            ..default()
+        },
+        Transform::from_xyz(deck_pos.x, deck_pos.y, 0.0),
+        GameEntity,
+    ))
+    .with_children(|parent| {
+        // Dotted border (we'll use a solid border with transparency for now)
+        parent.spawn((
+            Sprite {
+                color: Color::srgba(0.3, 0.3, 0.4, 0.5),  // Semi-transparent border
+                custom_size: Some(card_size),
+                ..default()
+            },
+            Transform::from_xyz(0.0, 0.0, -0.05),
+        ));
+
+        // Inner border to create outline effect
+        parent.spawn((
+            Sprite {
+                color: Color::srgba(0.1, 0.1, 0.15, 0.0),  // Transparent inside
+                custom_size: Some(card_size - Vec2::splat(10.0)),
+                ..default()
+            },
+            Transform::from_xyz(0.0, 0.0, -0.04),
+        ));
+
+        // "deck" text
+        parent.spawn((
+            Text2d::new("deck"),
+            TextFont {
+                font_size: 32.0,
+                ..default()
+            },
+            TextColor(Color::srgba(0.3, 0.3, 0.4, 0.6)),
+            Transform::from_xyz(0.0, 0.0, 0.1),
+        ));
+    });
+}
+
+// Spawn the back of a face-down card as children of the card entity, border first
+fn spawn_card_back(parent: &mut ChildSpawnerCommands, card_size: Vec2) {
+    // Card back border
+    parent.spawn((
+        Sprite {
+            color: Color::srgb(0.5, 0.4, 0.35),  // Border color
+            custom_size: Some(card_size + Vec2::splat(6.0)),
+            ..default()
+        },
+        Transform::from_xyz(0.0, 0.0, -0.1),
+    ));
+
+    // Inner panel of the card back
+    parent.spawn((
+        Sprite {
+            color: Color::srgb(0.6, 0.5, 0.45),
+            custom_size: Some(card_size * 0.8),
+            ..default()
+        },
+        Transform::from_xyz(0.0, 0.0, 0.01),
+    ));
+}
+
+// Cards on screen follow the match state: cards that reach a hand or the board get an entity,
+// cards that change zone move (turning face up or down as needed), and cards that leave for a
+// deck or pile are despawned. Board cards take the size of their slot. Slots are marked
+// occupied to match the board.
+pub fn sync_cards_system(
+    mut commands: Commands,
+    gameplay_state: Res<GameplayState>,
+    mut card_query: Query<(Entity, &mut Card, &mut CardZone, &mut Transform, &mut Sprite)>,
+    mut slot_query: Query<(&mut CardSlot, &Transform, &Sprite), Without<Card>>,
+    asset_server: Res<AssetServer>,
+    resolution: Res<VirtualResolution>,
+) {
+    if !gameplay_state.is_changed() {
         return;
-    };
+    }
 
-    // Check if deck was clicked
-    if let Ok((deck_entity, deck_transform, deck_sprite, deck_children)) = deck_query.single() {
-        let Some(deck_size) = deck_sprite.custom_size else {
-            return;
+    let layout = LayoutZones::new(&resolution);
+    let slot_positions: HashMap<CardZone, Vec2> = slot_query
+        .iter()
+        .map(|(slot, transform, _)| (slot.zone, transform.translation.truncate()))
+        .collect();
+    let slot_sizes: HashMap<CardZone, Vec2> = slot_query
+        .iter()
+        .filter_map(|(slot, _, sprite)| Some((slot.zone, sprite.custom_size?)))
+        .collect();
+
+    // Every card that should be on screen and where
+    let mut shown: Vec<(&CardInstance, CardZone)> = Vec::new();
+    shown.extend(gameplay_state.player.hand.iter().map(|card| (card, CardZone::PlayerHand)));
+    shown.extend(gameplay_state.opponent.hand.iter().map(|card| (card, CardZone::OpponentHand)));
+    for side in [PlayerSide::Player, PlayerSide::Opponent] {
+        for (slot, unit) in gameplay_state.side(side).board.iter().enumerate() {
+            if let Some(unit) = unit {
+                shown.push((&unit.card, slot_zone(side, slot)));
+            }
+        }
+    }
+
+    let mut entities: HashMap<CardId, Entity> = card_query
+        .iter()
+        .map(|(entity, card, _, _, _)| (card.id, entity))
+        .collect();
+
+    for (instance, zone) in shown {
+        let hand_index = match zone {
+            CardZone::PlayerHand => gameplay_state.player.hand.iter().position(|card| card.id == instance.id),
+            _ => None,
         };
 
-        let deck_pos = deck_transform.translation.truncate();
-        let half_size = deck_size / 2.0;
-
-        let clicked_deck = cursor_world_pos.x >= deck_pos.x - half_size.x &&
-                          cursor_world_pos.x <= deck_pos.x + half_size.x &&
-                          cursor_world_pos.y >= deck_pos.y - half_size.y &&
-                          cursor_world_pos.y <= deck_pos.y + half_size.y;
-
-        if clicked_deck {
-            // Try to draw a card from the gameplay state
-            if let Some(card_data) = gameplay_state.draw_card() {
-
-            // Card size: Use viewport height as reference for consistent scaling
-            // Card height: 40% of viewport height, width: 2:3 aspect ratio
-            let card_height = window.height() * 0.40;
-            let card_width = card_height * (2.0 / 3.0);
-            let card_size = Vec2::new(card_width, card_height);
-
-            let hand_index = hand_query.iter().count();
-            let hand_count = hand_index + 1;
-
-            // Calculate z position: cards on the left (lower index) should be in front
-            // Use larger z increments (10.0 instead of 0.1) to ensure proper layering
-            let z = (hand_count - hand_index) as f32 * 10.0;
-
-            // Generate a random color for the card
-            use rand::Rng;
-            #[allow(deprecated)]
-            let mut rng = rand::thread_rng();
-            #[allow(deprecated)]
-            let card_color = Color::srgb(
-                rng.gen_range(0.5..1.0),
-                rng.gen_range(0.5..1.0),
-                rng.gen_range(0.5..1.0),
+        let Some(entity) = entities.remove(&instance.id) else {
+            spawn_card(
+                &mut commands,
+                instance,
+                zone,
+                hand_index,
+                slot_positions.get(&zone).copied(),
+                slot_sizes.get(&zone).copied().unwrap_or(layout.card_size),
+                &layout,
+                &resolution,
+                &asset_server,
             );
+            continue;
+        };
+
+        let Ok((_, mut card, mut current_zone, mut transform, mut sprite)) = card_query.get_mut(entity) else {
+            continue;
+        };
+        if *current_zone == zone {
+            continue;
+        }
+
+        let was_face_down = *current_zone == CardZone::OpponentHand;
+        let was_on_board = zone_slot(&current_zone).is_some();
+        *current_zone = zone;
+
+        let mut entity_commands = commands.entity(entity);
+        match hand_index {
+            Some(hand_index) => entity_commands.insert(InHand { hand_index }),
+            None => entity_commands.remove::<InHand>(),
+        };
+
+        // Board cards take their slot's size; everywhere else cards are full size
+        let card_size = slot_sizes.get(&zone).copied().unwrap_or(layout.card_size);
+        let resized = card.base_size != card_size;
+        if resized {
+            card.base_size = card_size;
+            sprite.custom_size = Some(card_size);
+        }
+
+        // Turn the card over when it enters or leaves the opponent's hand, and redraw the face
+        // when its size changes or it comes back to the hand, so damage and buffs no longer show
+        let face_down = zone == CardZone::OpponentHand;
+        if face_down && !was_face_down {
+            sprite.color = CARD_BACK_COLOR;
+            entity_commands
+                .insert(OpponentHandCard)
+                .despawn_related::<Children>()
+                .with_children(|parent| spawn_card_back(parent, card_size));
+        } else if was_face_down && !face_down {
+            sprite.color = CardTheme::of(&card.data).frame;
+            let card_data = card.data.clone();
+            entity_commands
+                .remove::<OpponentHandCard>()
+                .despawn_related::<Children>()
+                .with_children(|parent| spawn_card_face(parent, &card_data, card_size, &asset_server));
+        } else if resized || was_on_board && zone == CardZone::PlayerHand {
+            let card_data = card.data.clone();
+            entity_commands
+                .despawn_related::<Children>()
+                .with_children(|parent| spawn_card_face(parent, &card_data, card_size, &asset_server));
+        }
+
+        // Board cards sit upright in their slot; hand layout systems place the rest
+        if let Some(&slot_pos) = slot_positions.get(&zone) {
+            card.target_position = slot_pos;
+            transform.rotation = Quat::IDENTITY;
+        }
+    }
 
-            // Spawn the new card
-            let card_entity = commands.spawn((
-                Card::new(card_data.clone(), card_size),
-                InHand { hand_index },
+    // Whatever is left went to a deck, graveyard or exile
+    for entity in entities.into_values() {
+        commands.entity(entity).despawn();
+    }
+
+    for (mut slot, _, _) in slot_query.iter_mut() {
+        let occupied = zone_slot(&slot.zone).is_some_and(|(side, index)| {
+            gameplay_state.side(side).board.get(index).is_some_and(Option::is_some)
+        });
+        if slot.occupied != occupied {
+            slot.occupied = occupied;
+        }
+    }
+}
+
+// Spawn the entity for a card that has just become visible: face up in the player's hand
+// or on the board, face down in the opponent's hand
+fn spawn_card(
+    commands: &mut Commands,
+    instance: &CardInstance,
+    zone: CardZone,
+    hand_index: Option<usize>,
+    slot_pos: Option<Vec2>,
+    card_size: Vec2,
+    layout: &LayoutZones,
+    resolution: &VirtualResolution,
+    asset_server: &AssetServer,
+) {
+    let mut card = Card::new(instance, card_size);
+
+    if zone == CardZone::OpponentHand {
+        // Cards slide in from above the top edge
+        let spawn_y = resolution.height / 2.0 + layout.card_height;
+        card.target_position = Vec2::new(0.0, spawn_y);
+
+        commands
+            .spawn((
+                card,
+                OpponentHandCard,
+                zone,
                 Sprite {
-                    color: card_color,
+                    color: CARD_BACK_COLOR,
                     custom_size: Some(card_size),
                     ..default()
                 },
-                Transform::from_xyz(0.0, -250.0, z),
+                Transform::from_xyz(0.0, spawn_y, 0.0),
                 GameEntity,
             ))
-            .with_children(|parent| {
-                // Card border (behind the card)
-                parent.spawn((
-                    Sprite {
-                        color: Color::srgb(0.3, 0.3, 0.4),
-                        custom_size: Some(card_size + Vec2::splat(6.0)),
-                        ..default()
-                    },
-                    Transform::from_xyz(0.0, 0.0, -1.0),
-                ));
-
-                // Card text (in front of the card but still relative to parent)
-                parent.spawn((
-                    Text2d::new(&card_data.name),
-                    TextFont {
-                        font_size: 28.0,
-                        ..default()
-                    },
-                    TextColor(Color::srgb(0.1, 0.1, 0.15)),
-                    Transform::from_xyz(0.0, 0.0, 0.01),
-                    CardText,
-                ));
-            })
-            .id(); // Get the entity ID
+            .with_children(|parent| spawn_card_back(parent, card_size));
+        return;
+    }
 
-            // Add the new card entity to gameplay state
-            gameplay_state.add_to_hand(card_entity);
-        }
+    // New hand cards rise from below the hand; board cards appear in their slot
+    let position = slot_pos.unwrap_or(Vec2::new(0.0, -250.0));
+    card.target_position = position;
 
-        // If deck is now empty, replace with empty deck placeholder
-        if gameplay_state.deck.is_empty() {
-                // Despawn children first
-                for child in deck_children.iter() {
-                    commands.entity(child).despawn();
-                }
-                // Then despawn the deck entity
-                commands.entity(deck_entity).despawn();
-
-                let deck_pos = deck_transform.translation;
-
-                // Card size: Use viewport height as reference for consistent scaling
-                let card_height = window.height() * 0.40;
-                let card_width = card_height * (2.0 / 3.0);
-                let card_size = Vec2::new(card_width, card_height);
-
-                // Deck position: Scale with card size
-                let deck_offset = card_width * 0.1;
-                let deck_offset_x = -(card_width * 0.5 + deck_offset);
-                let deck_offset_y = card_height * 0.5 + deck_offset;
-
-                // Spawn empty deck placeholder
-                commands.spawn((
-                    DeckEmpty,
-                    AnchorPosition::BottomRight {
-                        offset_x: deck_offset_x,
-                        offset_y: deck_offset_y,
-                    },
-                    Sprite {
-                        color: Color::NONE,  // Transparent background
-                        custom_size: Some(card_size),
-                        ..default()
-                    },
-                    Transform::from_xyz(deck_pos.x, deck_pos.y, 0.0),
-                    GameEntity,
-                ))
-                .with_children(|parent| {
-                    // Dotted border (we'll use a solid border with transparency for now)
-                    parent.spawn((
-                        Sprite {
-                            color: Color::srgba(0.3, 0.3, 0.4, 0.5),  // Semi-transparent border
-                            custom_size: Some(card_size),
-                            ..default()
-                        },
-                        Transform::from_xyz(0.0, 0.0, -0.05),
-                    ));
-
-                    // Inner border to create outline effect
-                    parent.spawn((
-                        Sprite {
-                            color: Color::srgba(0.1, 0.1, 0.15, 0.0),  // Transparent inside
-                            custom_size: Some(card_size - Vec2::splat(10.0)),
-                            ..default()
-                        },
-                        Transform::from_xyz(0.0, 0.0, -0.04),
-                    ));
-
-                    // "deck" text
-                    parent.spawn((
-                        Text2d::new("deck"),
-                        TextFont {
-                            font_size: 32.0,
-                            ..default()
-                        },
-                        TextColor(Color::srgba(0.3, 0.3, 0.4, 0.6)),
-                        Transform::from_xyz(0.0, 0.0, 0.1),
-                    ));
-                });
-            }
-        }
+    let mut entity_commands = commands.spawn((
+        card,
+        zone,
+        Sprite {
+            color: CardTheme::of(&instance.data).frame,
+            custom_size: Some(card_size),
+            ..default()
+        },
+        Transform::from_xyz(position.x, position.y, 0.0),
+        GameEntity,
+    ));
+    entity_commands.with_children(|parent| spawn_card_face(parent, &instance.data, card_size, asset_server));
+    if let Some(hand_index) = hand_index {
+        entity_commands.insert(InHand { hand_index });
     }
 }
 
 // System to arrange cards in hand in a splayed arc
 pub fn hand_layout_system(
-    mut hand_query: Query<(&InHand, &mut Card, &mut Transform, Option<&Dragging>)>,
-    window_dims: Res<WindowDimensions>,
+    mut hand_query: Query<(&mut InHand, &mut Card, &mut Transform, Option<&Dragging>, Has<TargetingSource>)>,
+    gameplay_state: Res<GameplayState>,
+    resolution: Res<VirtualResolution>,
 ) {
-    let hand_count = hand_query.iter().count();
+    let hand = &gameplay_state.player.hand;
+    let hand_count = hand.len();
     if hand_count == 0 {
         return;
     }
 
     // Hand layout parameters: Scale everything relative to viewport height for consistency
-    let card_height = window_dims.height * 0.40;
-    let card_width = card_height * (2.0 / 3.0);
-
-    // Spacing based on card width for proportional layout
-    let card_spacing = card_width * 0.4;  // 40% of card width between cards
-    let arc_height = card_height * 0.1;   // 10% of card height for arc
-    let rotation_per_card = 0.08;         // Rotation in radians per card from center
-    let hover_spread = card_width * 0.3;  // 30% of card width for hover spread
+    let layout = LayoutZones::new(&resolution);
 
     // Calculate hand position so the BOTTOM of cards stays at consistent distance from bottom
-    // We want the bottom of the lowest card to be 2.5% of viewport height from bottom
-    let card_half_height = card_height / 2.0;
-    let bottom_margin = window_dims.height * 0.025;  // 2.5% from bottom
-    let hand_y = -(window_dims.height / 2.0) + bottom_margin + card_half_height;
+    let hand_y = layout.player_hand_y(&resolution);
+
+    // Hand order comes from the gameplay state, so cards leaving the hand close the gap
+    let hand_position = |card: &Card| hand.iter().position(|held| held.id == card.id);
 
     // Find which card is hovered (if any)
     let hovered_index: Option<usize> = hand_query
         .iter()
-        .find(|(_, card, _, dragging)| card.is_hovered && dragging.is_none())
-        .map(|(in_hand, _, _, _)| in_hand.hand_index);
-
-    // Calculate total width and starting position
-    let total_width = (hand_count - 1) as f32 * card_spacing;
-    let start_x = -total_width / 2.0;
+        .find(|(_, card, _, dragging, _)| card.is_hovered && dragging.is_none())
+        .and_then(|(_, card, _, _, _)| hand_position(card));
 
-    for (in_hand, mut card, mut transform, dragging) in hand_query.iter_mut() {
-        // Skip cards that are being dragged
-        if dragging.is_some() {
+    for (mut in_hand, mut card, mut transform, dragging, targeting) in hand_query.iter_mut() {
+        let Some(hand_index) = hand_position(&card) else {
             continue;
+        };
+        if in_hand.hand_index != hand_index {
+            in_hand.hand_index = hand_index;
         }
 
-        let index = in_hand.hand_index;
-
-        // Calculate base position along the arc
-        let mut x_offset = index as f32 * card_spacing;
-
-        // Apply spreading effect when a card is hovered
-        if let Some(hovered_idx) = hovered_index {
-            if index > hovered_idx {
-                // Cards to the right of hovered card: push right
-                x_offset += hover_spread;
-            } else if index < hovered_idx {
-                // Cards to the left of hovered card: push left
-                x_offset -= hover_spread;
-            }
+        // Skip cards that are being dragged or are waiting for a target
+        if dragging.is_some() || targeting {
+            continue;
         }
 
-        let x = start_x + x_offset;
-
-        // Calculate arc (parabolic curve)
-        let center_offset = index as f32 - (hand_count - 1) as f32 / 2.0;
-        let normalized_offset = center_offset / ((hand_count as f32) / 2.0).max(1.0);
-        let y_offset = arc_height * (1.0 - normalized_offset * normalized_offset);
-        let y = hand_y + y_offset;
-
-        // Calculate rotation (cards fan outward)
-        let rotation = -center_offset * rotation_per_card;
+        let (offset, rotation) = layout.hand_arc(hand_index, hand_count, hovered_index);
 
         // Set target position for smooth interpolation by card_animation_system
-        card.target_position = Vec2::new(x, y);
+        card.target_position = Vec2::new(offset.x, hand_y + offset.y);
 
         // Update rotation directly (rotation changes instantly)
         // Note: x, y, and z positions are managed by card_animation_system
         transform.rotation = Quat::from_rotation_z(rotation);
     }
 }
+
+// System to arrange the opponent's face-down hand: the player's arc turned upside down
+pub fn opponent_hand_layout_system(
+    mut card_query: Query<(&mut Card, &mut Transform), With<OpponentHandCard>>,
+    gameplay_state: Res<GameplayState>,
+    resolution: Res<VirtualResolution>,
+) {
+    let hand = &gameplay_state.opponent.hand;
+    let hand_count = hand.len();
+    let layout = LayoutZones::new(&resolution);
+    let hand_y = layout.opponent_hand_y(&resolution);
+
+    for (mut card, mut transform) in card_query.iter_mut() {
+        let Some(index) = hand.iter().position(|held| held.id == card.id) else {
+            continue;
+        };
+
+        let (offset, rotation) = layout.hand_arc(index, hand_count, None);
+
+        card.target_position = Vec2::new(-offset.x, hand_y - offset.y);
+        transform.rotation = Quat::from_rotation_z(rotation + std::f32::consts::PI);
+        transform.translation.z = (hand_count - index) as f32 * 10.0;
+    }
+}
+
+// The active player's draw step: they draw a card, or lose if their deck is empty and
+// the deck-out rule is on
+pub fn draw_step_system(mut gameplay_state: ResMut<GameplayState>) {
+    gameplay_state.draw_step();
+}
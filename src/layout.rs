@@ -0,0 +1,45 @@
+use bevy::prelude::*;
+use bevy::window::WindowResized;
+use crate::InMatch;
+use crate::gameplay::AnchorPosition;
+use crate::startup::VirtualResolution;
+
+// Plugin initializer for keeping the board laid out to the window as it is resized
+pub fn init_layout_systems(app: &mut App) {
+    app.add_systems(
+        Update,
+        (
+            window_resize_system,
+            anchor_layout_system.run_if(in_state(InMatch).and(resource_changed::<VirtualResolution>)),
+        )
+            .chain(),
+    );
+}
+
+// Follow the window's aspect ratio in the virtual resolution. Its height is fixed, so resizing
+// only changes how wide the world is; cards and slots, sized from the height, stay as they are.
+pub fn window_resize_system(
+    mut resize_reader: MessageReader<WindowResized>,
+    mut resolution: ResMut<VirtualResolution>,
+) {
+    // Only the latest size matters; a minimized window reports zero and keeps the old layout
+    let Some(resized) = resize_reader.read().last() else {
+        return;
+    };
+    if resized.width <= 0.0 || resized.height <= 0.0 {
+        return;
+    }
+    resolution.set_if_neq(VirtualResolution::for_window(resized.width, resized.height));
+}
+
+// Keep anchored entities on their edge or corner of the window
+pub fn anchor_layout_system(
+    resolution: Res<VirtualResolution>,
+    mut anchor_query: Query<(&AnchorPosition, &mut Transform)>,
+) {
+    for (anchor, mut transform) in anchor_query.iter_mut() {
+        let position = anchor.resolve(&resolution);
+        transform.translation.x = position.x;
+        transform.translation.y = position.y;
+    }
+}
@@ -1,16 +1,53 @@
 use bevy::prelude::*;
 
 mod startup;
+mod cards;
+mod card_face;
+mod decks;
+mod rng;
 mod menu;
 mod options;
 mod pause;
 mod gameplay;
+mod layout;
+mod picking;
+mod turn;
+mod mulligan;
+mod discard;
+mod mana;
+mod combat;
+mod piles;
+mod abilities;
+mod targeting;
+mod stack;
+mod scripting;
+mod ai;
+mod mcts;
+mod opponent;
+mod game_over;
 
 use startup::*;
+use cards::*;
+use decks::*;
+use rng::*;
 use menu::*;
 use options::*;
 use pause::*;
 use gameplay::*;
+use layout::*;
+use turn::*;
+use mulligan::*;
+use discard::*;
+use mana::*;
+use combat::*;
+use piles::*;
+use abilities::*;
+use targeting::*;
+use stack::*;
+use scripting::*;
+use mcts::*;
+use opponent::*;
+use game_over::*;
 
 fn main() {
     let mut app = App::new();
@@ -28,14 +65,32 @@ fn main() {
             hover_scale: 1.3,
             animation_speed: 5.0,
         })
-        .init_state::<GameState>();
+        .init_state::<GameState>()
+        .add_computed_state::<InMatch>();
 
     // Initialize systems from each module
     init_startup_systems(&mut app);
+    init_rng_systems(&mut app);
+    init_card_systems(&mut app);
+    init_deck_systems(&mut app);
     init_menu_systems(&mut app);
     init_options_systems(&mut app);
     init_pause_systems(&mut app);
     init_gameplay_systems(&mut app);
+    init_layout_systems(&mut app);
+    init_turn_systems(&mut app);
+    init_mulligan_systems(&mut app);
+    init_discard_systems(&mut app);
+    init_mana_systems(&mut app);
+    init_combat_systems(&mut app);
+    init_pile_systems(&mut app);
+    init_ability_systems(&mut app);
+    init_targeting_systems(&mut app);
+    init_stack_systems(&mut app);
+    init_scripting_systems(&mut app);
+    init_mcts_systems(&mut app);
+    init_opponent_systems(&mut app);
+    init_game_over_systems(&mut app);
 
     app.run();
 }
@@ -48,6 +103,22 @@ pub enum GameState {
     Playing,
     Paused,
     Options,
+    GameOver,
+}
+
+// A match is in progress while playing or paused; the board is only torn down when it ends
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct InMatch;
+
+impl ComputedStates for InMatch {
+    type SourceStates = GameState;
+
+    fn compute(sources: GameState) -> Option<Self> {
+        match sources {
+            GameState::Playing | GameState::Paused => Some(InMatch),
+            _ => None,
+        }
+    }
 }
 
 // Card configuration resource
@@ -56,18 +127,3 @@ pub struct CardConfig {
     pub hover_scale: f32,
     pub animation_speed: f32,
 }
-
-// Card data structure
-#[derive(Clone, Debug)]
-pub struct CardData {
-    pub name: String,
-    // Future fields can be added here: cost, effect, image, etc.
-}
-
-impl CardData {
-    pub fn new(name: impl Into<String>) -> Self {
-        Self {
-            name: name.into(),
-        }
-    }
-}
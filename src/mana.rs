@@ -0,0 +1,120 @@
+use bevy::prelude::*;
+use cardigan_core::PlayerSide;
+use crate::{GameState, InMatch};
+use crate::gameplay::{Card, GameEntity, GameplayState};
+
+// How long a rejected play stays highlighted
+const REJECTED_PLAY_SECONDS: f32 = 0.6;
+
+// Plugin initializer for mana systems
+pub fn init_mana_systems(app: &mut App) {
+    app.add_systems(OnEnter(InMatch), setup_mana_ui)
+        .add_systems(
+            Update,
+            (mana_label_system, rejected_play_feedback_system)
+                .run_if(in_state(GameState::Playing)),
+        );
+}
+
+// Component to mark a player's mana label
+#[derive(Component)]
+pub struct ManaLabel {
+    pub side: PlayerSide,
+}
+
+// Component for a card whose play was rejected because its cost couldn't be paid
+#[derive(Component)]
+pub struct RejectedPlay {
+    pub timer: Timer,
+}
+
+impl RejectedPlay {
+    pub fn new() -> Self {
+        Self {
+            timer: Timer::from_seconds(REJECTED_PLAY_SECONDS, TimerMode::Once),
+        }
+    }
+}
+
+// Setup mana labels on the left edge, opponent above the centre line and player below
+pub fn setup_mana_ui(mut commands: Commands) {
+    for (side, top_margin) in [(PlayerSide::Opponent, -70.0), (PlayerSide::Player, 20.0)] {
+        commands.spawn((
+            Text::new(""),
+            TextFont {
+                font_size: 28.0,
+                ..default()
+            },
+            TextColor(Color::srgb(0.55, 0.7, 1.0)),
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(20.0),
+                top: Val::Percent(50.0),
+                margin: UiRect::top(Val::Px(top_margin)),
+                ..default()
+            },
+            ManaLabel { side },
+            GameEntity,
+        ));
+    }
+}
+
+// Keep the mana labels in sync; the player's label turns red while a play is being rejected
+pub fn mana_label_system(
+    mut label_query: Query<(&ManaLabel, &mut Text, &mut TextColor)>,
+    gameplay_state: Res<GameplayState>,
+    rejected_query: Query<&RejectedPlay>,
+) {
+    let rejecting = !rejected_query.is_empty();
+
+    for (label, mut text, mut color) in label_query.iter_mut() {
+        let mana = gameplay_state.side(label.side).mana;
+        let who = match label.side {
+            PlayerSide::Player => "You",
+            PlayerSide::Opponent => "Opponent",
+        };
+        let value = format!("{}: {}/{} mana", who, mana.current, mana.max);
+        if text.0 != value {
+            text.0 = value;
+        }
+
+        color.0 = if rejecting && label.side == PlayerSide::Player {
+            Color::srgb(0.95, 0.3, 0.3)
+        } else {
+            Color::srgb(0.55, 0.7, 1.0)
+        };
+    }
+}
+
+// Flash the border of a card that couldn't be paid for, then restore it
+pub fn rejected_play_feedback_system(
+    mut commands: Commands,
+    mut card_query: Query<(Entity, &Card, &Children, &mut RejectedPlay)>,
+    mut sprite_query: Query<&mut Sprite, Without<Card>>,
+    time: Res<Time>,
+) {
+    for (entity, card, children, mut rejected) in card_query.iter_mut() {
+        rejected.timer.tick(time.delta());
+
+        // Pulse between red and the normal border color
+        let border_color = if rejected.timer.is_finished() {
+            commands.entity(entity).remove::<RejectedPlay>();
+            if card.is_hovered {
+                Color::srgb(0.4, 0.6, 0.9)
+            } else {
+                Color::srgb(0.3, 0.3, 0.4)
+            }
+        } else if ((rejected.timer.elapsed_secs() * 10.0) as u32).is_multiple_of(2) {
+            Color::srgb(0.95, 0.2, 0.2)
+        } else {
+            Color::srgb(0.5, 0.15, 0.15)
+        };
+
+        // First child is the border
+        if let Some(&border_entity) = children.first()
+            && let Ok(mut border_sprite) = sprite_query.get_mut(border_entity)
+        {
+            border_sprite.color = border_color;
+        }
+    }
+}
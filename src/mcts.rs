@@ -0,0 +1,307 @@
+use std::time::{Duration, Instant};
+use bevy::prelude::*;
+use cardigan_core::{Action, MatchState, PlayerSide, Unit};
+use rand::seq::SliceRandom;
+use rand::{Rng, RngCore};
+use crate::ai::{GreedyAi, OpponentAi, RandomAi};
+
+// Environment variable overriding the search budget: a number of iterations (`4000`) or a
+// time limit in milliseconds (`750ms`)
+pub const BUDGET_ENV_VAR: &str = "CARDIGAN_AI_BUDGET";
+
+// UCT exploration constant
+const EXPLORATION: f32 = 1.4;
+
+// Rollouts stop after this many turns and score the position instead
+const MAX_ROLLOUT_TURNS: u32 = 16;
+
+// Chance that a rollout move is random rather than greedy
+const ROLLOUT_RANDOMNESS: f64 = 0.25;
+
+// How long the search may run per decision
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchBudget {
+    // Deterministic: a fixed seed replays the same game
+    Iterations(u32),
+    // Stable thinking time, but results depend on machine speed
+    Time(Duration),
+}
+
+// Settings for the Monte Carlo tree search opponent
+#[derive(Resource, Debug, Clone)]
+pub struct MctsSettings {
+    pub budget: SearchBudget,
+    // The opponent's hidden cards are guessed this many times; the budget is split between them
+    pub determinizations: u32,
+}
+
+impl Default for MctsSettings {
+    fn default() -> Self {
+        Self {
+            budget: SearchBudget::Iterations(3000),
+            determinizations: 6,
+        }
+    }
+}
+
+impl MctsSettings {
+    pub fn from_env() -> Self {
+        let mut settings = Self::default();
+        if let Ok(value) = std::env::var(BUDGET_ENV_VAR) {
+            let value = value.trim();
+            let budget = match value.strip_suffix("ms") {
+                Some(millis) => millis
+                    .trim()
+                    .parse()
+                    .ok()
+                    .map(|millis| SearchBudget::Time(Duration::from_millis(millis))),
+                None => value.parse().ok().map(SearchBudget::Iterations),
+            };
+            match budget {
+                Some(budget) => settings.budget = budget,
+                None => warn!(
+                    "Ignoring {}={:?}: expected an iteration count or milliseconds like 500ms",
+                    BUDGET_ENV_VAR, value
+                ),
+            }
+        }
+        settings
+    }
+}
+
+// Plugin initializer for the search opponent's settings
+pub fn init_mcts_systems(app: &mut App) {
+    app.insert_resource(MctsSettings::from_env());
+}
+
+// Monte Carlo tree search over the headless rules. The player's hand and both decks are
+// hidden, so each search tree plays out a different guess at them ("determinization")
+// and the trees vote on the first move.
+pub struct MctsAi {
+    settings: MctsSettings,
+}
+
+impl MctsAi {
+    pub fn new(settings: MctsSettings) -> Self {
+        Self { settings }
+    }
+}
+
+impl OpponentAi for MctsAi {
+    fn choose_action(&mut self, state: &MatchState, rng: &mut dyn RngCore) -> Action {
+        let actions = state.legal_actions();
+        if actions.len() <= 1 {
+            return actions.into_iter().next().unwrap_or(Action::Attack { slots: Vec::new() });
+        }
+
+        let me = state.priority;
+        let trees = self.settings.determinizations.max(1);
+        let started = Instant::now();
+        let mut votes = vec![0u32; actions.len()];
+
+        for tree_index in 0..trees {
+            let mut world = state.clone();
+            determinize(&mut world, me, rng);
+            let mut tree = SearchTree::new(world);
+
+            let mut iterations = 0;
+            loop {
+                let done = match self.settings.budget {
+                    SearchBudget::Iterations(total) => iterations >= total.div_ceil(trees),
+                    SearchBudget::Time(limit) => {
+                        started.elapsed() >= limit.mul_f32((tree_index + 1) as f32 / trees as f32)
+                    }
+                };
+                if done {
+                    break;
+                }
+                tree.iterate(me, rng);
+                iterations += 1;
+            }
+
+            // The root's moves only depend on what the AI can see, so they line up across trees
+            for (action, visits) in tree.root_visits() {
+                if let Some(index) = actions.iter().position(|candidate| candidate == action) {
+                    votes[index] += visits;
+                }
+            }
+        }
+
+        let best = votes
+            .iter()
+            .enumerate()
+            .max_by_key(|(_, visits)| **visits)
+            .map(|(index, _)| index)
+            .unwrap_or(0);
+        actions[best].clone()
+    }
+}
+
+// Replace what `me` can't know with a plausible guess: the other player's hand and deck are
+// reshuffled together, and both decks are put in a random order
+fn determinize(state: &mut MatchState, me: PlayerSide, rng: &mut dyn RngCore) {
+    let hidden = state.side_mut(me.other());
+    let hand_size = hidden.hand.len();
+    let mut unknown: Vec<_> = hidden.hand.drain(..).chain(hidden.deck.drain(..)).collect();
+    unknown.shuffle(rng);
+    hidden.hand = unknown.split_off(unknown.len() - hand_size);
+    hidden.deck = unknown;
+
+    state.side_mut(me).deck.shuffle(rng);
+}
+
+struct Node {
+    parent: Option<usize>,
+    // The move that led here, and who made it
+    action: Option<Action>,
+    mover: PlayerSide,
+    children: Vec<usize>,
+    untried: Vec<Action>,
+    visits: u32,
+    // Sum of rewards from the mover's point of view
+    reward: f32,
+}
+
+struct SearchTree {
+    root_state: MatchState,
+    nodes: Vec<Node>,
+}
+
+impl SearchTree {
+    fn new(root_state: MatchState) -> Self {
+        let root = Node {
+            parent: None,
+            action: None,
+            mover: root_state.priority.other(),
+            children: Vec::new(),
+            untried: root_state.legal_actions(),
+            visits: 0,
+            reward: 0.0,
+        };
+        Self {
+            root_state,
+            nodes: vec![root],
+        }
+    }
+
+    // One round of selection, expansion, rollout and backpropagation
+    fn iterate(&mut self, me: PlayerSide, rng: &mut dyn RngCore) {
+        let mut state = self.root_state.clone();
+        let mut node = 0;
+
+        // Selection: follow the best UCT child while the node is fully expanded
+        while self.nodes[node].untried.is_empty() && !self.nodes[node].children.is_empty() {
+            node = self.best_child(node);
+            // Tree moves come from legal_actions, so they always apply
+            if let Some(action) = &self.nodes[node].action {
+                let _ = state.apply(action);
+            }
+        }
+
+        // Expansion: try one new move
+        if !self.nodes[node].untried.is_empty() {
+            let index = rng.random_range(0..self.nodes[node].untried.len());
+            let action = self.nodes[node].untried.swap_remove(index);
+            let mover = state.priority;
+            let _ = state.apply(&action);
+
+            let child = self.nodes.len();
+            self.nodes.push(Node {
+                parent: Some(node),
+                action: Some(action),
+                mover,
+                children: Vec::new(),
+                untried: state.legal_actions(),
+                visits: 0,
+                reward: 0.0,
+            });
+            self.nodes[node].children.push(child);
+            node = child;
+        }
+
+        // Rollout and backpropagation
+        let reward = rollout(state, me, rng);
+        let mut current = Some(node);
+        while let Some(index) = current {
+            let node = &mut self.nodes[index];
+            node.visits += 1;
+            node.reward += if node.mover == me { reward } else { 1.0 - reward };
+            current = node.parent;
+        }
+    }
+
+    fn best_child(&self, node: usize) -> usize {
+        let parent_visits = self.nodes[node].visits.max(1) as f32;
+        let uct = |child: usize| {
+            let child = &self.nodes[child];
+            let visits = child.visits.max(1) as f32;
+            child.reward / visits + EXPLORATION * (parent_visits.ln() / visits).sqrt()
+        };
+
+        self.nodes[node]
+            .children
+            .iter()
+            .copied()
+            .max_by(|&a, &b| uct(a).total_cmp(&uct(b)))
+            .unwrap_or(node)
+    }
+
+    // Visit counts of the root's moves
+    fn root_visits(&self) -> impl Iterator<Item = (&Action, u32)> {
+        self.nodes[0].children.iter().filter_map(|&child| {
+            let child = &self.nodes[child];
+            child.action.as_ref().map(|action| (action, child.visits))
+        })
+    }
+}
+
+// Play the match forward with a mostly-greedy policy and score the result for `me` in 0..=1
+fn rollout(mut state: MatchState, me: PlayerSide, rng: &mut dyn RngCore) -> f32 {
+    let last_turn = state.turn_number + MAX_ROLLOUT_TURNS;
+
+    while !state.is_over() && state.turn_number < last_turn {
+        let action = if rng.random_bool(ROLLOUT_RANDOMNESS) {
+            RandomAi.choose_action(&state, rng)
+        } else {
+            GreedyAi.choose_action(&state, rng)
+        };
+        if state.apply(&action).is_err() {
+            break;
+        }
+    }
+
+    match state.outcome.map(|outcome| outcome.winner) {
+        Some(Some(winner)) if winner == me => 1.0,
+        Some(Some(_)) => 0.0,
+        Some(None) => 0.5,
+        None => evaluate(&state, me),
+    }
+}
+
+// Score an unfinished match for `me` from life totals and the creatures on the board
+fn evaluate(state: &MatchState, me: PlayerSide) -> f32 {
+    let own = state.side(me);
+    let enemy = state.side(me.other());
+    let board_value = |board: &[Option<Unit>]| -> i64 {
+        board.iter().flatten().map(|unit| unit.value()).sum()
+    };
+
+    // Differences are taken in i64s, where life totals and stats at their limits still fit
+    let advantage = (i64::from(own.life) - i64::from(enemy.life)) as f32
+        + 0.5 * (board_value(&own.board) - board_value(&enemy.board)) as f32;
+    1.0 / (1.0 + (-advantage / 8.0).exp())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ai::tests::extreme_state;
+
+    #[test]
+    fn evaluate_scores_life_totals_at_their_limits() {
+        let state = extreme_state();
+
+        assert_eq!(evaluate(&state, PlayerSide::Player), 1.0);
+        assert_eq!(evaluate(&state, PlayerSide::Opponent), 0.0);
+    }
+}
@@ -1,6 +1,9 @@
 use bevy::prelude::*;
 use bevy::app::AppExit;
 use crate::GameState;
+use crate::ai::Difficulty;
+use crate::cards::CardLibrary;
+use crate::decks::{DeckList, MatchDecks, PlayerDecks};
 
 // Plugin initializer for menu systems
 pub fn init_menu_systems(app: &mut App) {
@@ -8,7 +11,8 @@ pub fn init_menu_systems(app: &mut App) {
         .add_systems(OnExit(GameState::Menu), cleanup_menu)
         .add_systems(
             Update,
-            (menu_button_system, menu_button_interaction).run_if(in_state(GameState::Menu)),
+            (menu_button_system, menu_button_interaction, difficulty_label_system)
+                .run_if(in_state(GameState::Menu)),
         );
 }
 
@@ -20,10 +24,19 @@ pub struct MenuEntity;
 #[derive(Component)]
 pub enum MenuButton {
     Play,
+    Difficulty,
     Options,
     Exit,
 }
 
+// Component to mark the difficulty picker's text
+#[derive(Component)]
+pub struct DifficultyLabel;
+
+// Component to mark the text explaining why a match can't start
+#[derive(Component)]
+pub struct MenuError;
+
 // Setup menu UI
 pub fn setup_menu(mut commands: Commands) {
     // Root node for the menu
@@ -82,6 +95,35 @@ pub fn setup_menu(mut commands: Commands) {
                     ));
                 });
 
+            // Difficulty picker; each click moves to the next difficulty
+            parent
+                .spawn((
+                    Button,
+                    Node {
+                        width: Val::Px(300.0),
+                        height: Val::Px(65.0),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        margin: UiRect::all(Val::Px(10.0)),
+                        border: UiRect::all(Val::Px(2.0)),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgb(0.15, 0.15, 0.2)),
+                    BorderColor::from(Color::srgb(0.4, 0.4, 0.5)),
+                    MenuButton::Difficulty,
+                ))
+                .with_children(|parent| {
+                    parent.spawn((
+                        Text::new(""),
+                        TextFont {
+                            font_size: 30.0,
+                            ..default()
+                        },
+                        TextColor(Color::srgb(0.9, 0.9, 0.95)),
+                        DifficultyLabel,
+                    ));
+                });
+
             // Options button
             parent
                 .spawn((
@@ -137,6 +179,23 @@ pub fn setup_menu(mut commands: Commands) {
                         TextColor(Color::srgb(0.9, 0.9, 0.95)),
                     ));
                 });
+
+            // Shown when PLAY can't start a match, e.g. because a deck list is broken
+            parent.spawn((
+                Text::new(""),
+                TextFont {
+                    font_size: 20.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.9, 0.35, 0.3)),
+                TextLayout::new_with_justify(Justify::Center),
+                Node {
+                    max_width: Val::Px(700.0),
+                    margin: UiRect::top(Val::Px(20.0)),
+                    ..default()
+                },
+                MenuError,
+            ));
         });
 }
 
@@ -174,15 +233,35 @@ pub fn menu_button_interaction(
 
 // Handle button clicks
 pub fn menu_button_system(
+    mut commands: Commands,
     interaction_query: Query<(&Interaction, &MenuButton), (Changed<Interaction>, With<Button>)>,
+    mut error_query: Query<&mut Text, With<MenuError>>,
     mut next_state: ResMut<NextState<GameState>>,
     mut exit: MessageWriter<AppExit>,
+    mut difficulty: ResMut<Difficulty>,
+    player_decks: Res<PlayerDecks>,
+    deck_lists: Res<Assets<DeckList>>,
+    library: Res<CardLibrary>,
+    asset_server: Res<AssetServer>,
 ) {
     for (interaction, button) in interaction_query.iter() {
         if *interaction == Interaction::Pressed {
             match button {
-                MenuButton::Play => {
-                    next_state.set(GameState::Playing);
+                // Both decks have to be playable before the match starts
+                MenuButton::Play => match MatchDecks::build(&player_decks, &deck_lists, &library, &asset_server) {
+                    Ok(match_decks) => {
+                        commands.insert_resource(match_decks);
+                        next_state.set(GameState::Playing);
+                    }
+                    Err(error) => {
+                        error!("Can't start a match: {}", error);
+                        for mut text in error_query.iter_mut() {
+                            text.0 = format!("Can't start a match: {}", error);
+                        }
+                    }
+                },
+                MenuButton::Difficulty => {
+                    *difficulty = difficulty.next();
                 }
                 MenuButton::Options => {
                     next_state.set(GameState::Options);
@@ -194,3 +273,17 @@ pub fn menu_button_system(
         }
     }
 }
+
+// Keep the difficulty picker text in sync with the chosen difficulty
+pub fn difficulty_label_system(
+    mut label_query: Query<&mut Text, With<DifficultyLabel>>,
+    difficulty: Res<Difficulty>,
+) {
+    let value = format!("OPPONENT: {}", difficulty.label());
+
+    for mut text in label_query.iter_mut() {
+        if text.0 != value {
+            text.0 = value.clone();
+        }
+    }
+}
@@ -0,0 +1,296 @@
+use bevy::prelude::*;
+use cardigan_core::{CardId, MulliganRule, PlayerSide};
+use crate::GameState;
+use crate::gameplay::{hovered_hand_card, Card, GameplayState, InHand};
+use crate::rng::GameRng;
+use crate::turn::TurnPhase;
+
+// Plugin initializer for keeping or redrawing the opening hand
+pub fn init_mulligan_systems(app: &mut App) {
+    app.add_systems(OnEnter(TurnPhase::Mulligan), setup_mulligan_ui)
+        .add_systems(OnExit(TurnPhase::Mulligan), cleanup_mulligan_ui)
+        .add_systems(
+            Update,
+            (
+                mulligan_pick_system,
+                mulligan_mark_system,
+                mulligan_button_system,
+                mulligan_button_interaction,
+                mulligan_label_system,
+            )
+                .run_if(in_state(GameState::Playing).and(in_state(TurnPhase::Mulligan))),
+        );
+}
+
+// Hand cards the player has picked to shuffle back (partial rule only)
+#[derive(Resource, Default)]
+pub struct MulliganPicks(pub Vec<CardId>);
+
+// Marker component for the mulligan panel
+#[derive(Component)]
+pub struct MulliganEntity;
+
+// Marker for the shade over a picked card
+#[derive(Component)]
+pub struct MulliganMark;
+
+// Component for the mulligan panel's buttons
+#[derive(Component, Clone, Copy, PartialEq, Eq)]
+pub enum MulliganButton {
+    Keep,
+    // The picked cards under the partial rule, the whole hand under the full rule
+    Redraw,
+}
+
+// Component to mark the redraw button's text, which counts the picked cards
+#[derive(Component)]
+pub struct RedrawLabel;
+
+// Setup the mulligan panel in the middle of the board, above the opening hand
+pub fn setup_mulligan_ui(mut commands: Commands, gameplay_state: Res<GameplayState>) {
+    commands.init_resource::<MulliganPicks>();
+
+    let hint = match gameplay_state.rules.mulligan {
+        MulliganRule::Partial => "Click cards to shuffle them back and draw replacements",
+        MulliganRule::Full => "Keep this hand, or shuffle it all back for a new one",
+    };
+
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                top: Val::Percent(30.0),
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+            MulliganEntity,
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn((
+                    Node {
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Center,
+                        padding: UiRect::all(Val::Px(20.0)),
+                        row_gap: Val::Px(12.0),
+                        border: UiRect::all(Val::Px(2.0)),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgba(0.12, 0.12, 0.17, 0.9)),
+                    BorderColor::from(Color::srgb(0.4, 0.4, 0.5)),
+                ))
+                .with_children(|parent| {
+                    parent.spawn((
+                        Text::new("OPENING HAND"),
+                        TextFont {
+                            font_size: 40.0,
+                            ..default()
+                        },
+                        TextColor(Color::srgb(0.9, 0.9, 0.95)),
+                    ));
+
+                    parent.spawn((
+                        Text::new(hint),
+                        TextFont {
+                            font_size: 20.0,
+                            ..default()
+                        },
+                        TextColor(Color::srgb(0.7, 0.7, 0.75)),
+                    ));
+
+                    parent
+                        .spawn(Node {
+                            column_gap: Val::Px(20.0),
+                            ..default()
+                        })
+                        .with_children(|parent| {
+                            for (button, label) in [(MulliganButton::Keep, "KEEP HAND"), (MulliganButton::Redraw, "")] {
+                                parent
+                                    .spawn((
+                                        Button,
+                                        Node {
+                                            width: Val::Px(260.0),
+                                            height: Val::Px(55.0),
+                                            justify_content: JustifyContent::Center,
+                                            align_items: AlignItems::Center,
+                                            border: UiRect::all(Val::Px(2.0)),
+                                            ..default()
+                                        },
+                                        BackgroundColor(Color::srgb(0.15, 0.15, 0.2)),
+                                        BorderColor::from(Color::srgb(0.4, 0.4, 0.5)),
+                                        button,
+                                    ))
+                                    .with_children(|parent| {
+                                        let mut text = parent.spawn((
+                                            Text::new(label),
+                                            TextFont {
+                                                font_size: 28.0,
+                                                ..default()
+                                            },
+                                            TextColor(Color::srgb(0.9, 0.9, 0.95)),
+                                        ));
+                                        if button == MulliganButton::Redraw {
+                                            text.insert(RedrawLabel);
+                                        }
+                                    });
+                            }
+                        });
+                });
+        });
+}
+
+// Remove the panel, the shades and the picks once the first turn starts (or the match ends)
+pub fn cleanup_mulligan_ui(
+    mut commands: Commands,
+    mulligan_entities: Query<Entity, Or<(With<MulliganEntity>, With<MulliganMark>)>>,
+) {
+    for entity in mulligan_entities.iter() {
+        commands.entity(entity).despawn();
+    }
+    commands.remove_resource::<MulliganPicks>();
+}
+
+// Under the partial rule, clicking a hand card picks it to shuffle back, or unpicks it
+pub fn mulligan_pick_system(
+    card_query: Query<&Card, With<InHand>>,
+    gameplay_state: Res<GameplayState>,
+    mut picks: ResMut<MulliganPicks>,
+    mouse_button: Res<ButtonInput<MouseButton>>,
+) {
+    if gameplay_state.rules.mulligan != MulliganRule::Partial
+        || gameplay_state.player.mulligan_done
+        || !mouse_button.just_pressed(MouseButton::Left)
+    {
+        return;
+    }
+
+    let Some(card_id) = hovered_hand_card(&card_query) else {
+        return;
+    };
+    match picks.0.iter().position(|&id| id == card_id) {
+        Some(index) => {
+            picks.0.remove(index);
+        }
+        None => picks.0.push(card_id),
+    }
+}
+
+// Shade the picked cards
+pub fn mulligan_mark_system(
+    mut commands: Commands,
+    picks: Res<MulliganPicks>,
+    card_query: Query<(Entity, &Card, Option<&Children>), With<InHand>>,
+    mark_query: Query<Entity, With<MulliganMark>>,
+) {
+    for (entity, card, children) in card_query.iter() {
+        let picked = picks.0.contains(&card.id);
+        let mark = children
+            .into_iter()
+            .flat_map(|children| children.iter())
+            .find(|child| mark_query.contains(*child));
+
+        match (picked, mark) {
+            (true, None) => {
+                commands.entity(entity).with_children(|parent| {
+                    parent
+                        .spawn((
+                            Sprite {
+                                color: Color::srgba(0.05, 0.05, 0.1, 0.6),
+                                custom_size: Some(card.base_size),
+                                ..default()
+                            },
+                            Transform::from_xyz(0.0, 0.0, 0.5),
+                            MulliganMark,
+                        ))
+                        .with_children(|parent| {
+                            parent.spawn((
+                                Text2d::new("REDRAW"),
+                                TextFont {
+                                    font_size: 28.0,
+                                    ..default()
+                                },
+                                TextColor(Color::srgb(1.0, 0.85, 0.3)),
+                                Transform::from_xyz(0.0, 0.0, 0.01),
+                            ));
+                        });
+                });
+            }
+            (false, Some(mark)) => commands.entity(mark).despawn(),
+            _ => {}
+        }
+    }
+}
+
+// Keep the hand, or shuffle back the picked cards (the whole hand under the full rule)
+pub fn mulligan_button_system(
+    interaction_query: Query<(&Interaction, &MulliganButton), Changed<Interaction>>,
+    mut gameplay_state: ResMut<GameplayState>,
+    picks: Res<MulliganPicks>,
+    mut rng: ResMut<GameRng>,
+) {
+    for (interaction, button) in interaction_query.iter() {
+        if *interaction != Interaction::Pressed || gameplay_state.player.mulligan_done {
+            continue;
+        }
+
+        let hand = &gameplay_state.player.hand;
+        let hand_indexes: Vec<usize> = match (button, gameplay_state.rules.mulligan) {
+            (MulliganButton::Keep, _) => Vec::new(),
+            (MulliganButton::Redraw, MulliganRule::Full) => (0..hand.len()).collect(),
+            (MulliganButton::Redraw, MulliganRule::Partial) => (0..hand.len())
+                .filter(|&index| picks.0.contains(&hand[index].id))
+                .collect(),
+        };
+        if *button == MulliganButton::Redraw && hand_indexes.is_empty() {
+            continue;
+        }
+
+        if let Err(error) = gameplay_state.mulligan(PlayerSide::Player, &hand_indexes, &mut *rng) {
+            warn!("Couldn't mulligan: {}", error);
+        }
+    }
+}
+
+// Mulligan button colors
+pub fn mulligan_button_interaction(
+    mut interaction_query: Query<
+        (&Interaction, &mut BackgroundColor, &mut BorderColor),
+        (Changed<Interaction>, With<MulliganButton>),
+    >,
+) {
+    for (interaction, mut bg_color, mut border_color) in interaction_query.iter_mut() {
+        match *interaction {
+            Interaction::Pressed => {
+                *bg_color = BackgroundColor(Color::srgb(0.25, 0.25, 0.3));
+                *border_color = BorderColor::from(Color::srgb(0.6, 0.6, 0.7));
+            }
+            Interaction::Hovered => {
+                *bg_color = BackgroundColor(Color::srgb(0.2, 0.2, 0.25));
+                *border_color = BorderColor::from(Color::srgb(0.7, 0.7, 0.8));
+            }
+            Interaction::None => {
+                *bg_color = BackgroundColor(Color::srgb(0.15, 0.15, 0.2));
+                *border_color = BorderColor::from(Color::srgb(0.4, 0.4, 0.5));
+            }
+        }
+    }
+}
+
+// Keep the redraw button's text in step with the rule and the picked cards
+pub fn mulligan_label_system(
+    mut label_query: Query<&mut Text, With<RedrawLabel>>,
+    gameplay_state: Res<GameplayState>,
+    picks: Res<MulliganPicks>,
+) {
+    let value = match gameplay_state.rules.mulligan {
+        MulliganRule::Full => "REDRAW ALL".to_string(),
+        MulliganRule::Partial => format!("REDRAW {}", picks.0.len()),
+    };
+
+    for mut text in label_query.iter_mut() {
+        if text.0 != value {
+            text.0 = value.clone();
+        }
+    }
+}
@@ -0,0 +1,161 @@
+use bevy::prelude::*;
+use bevy::tasks::futures::check_ready;
+use bevy::tasks::{AsyncComputeTaskPool, Task};
+use cardigan_core::{Action, PlayerSide};
+use rand::rngs::StdRng;
+use rand::{RngCore, SeedableRng};
+use crate::{GameState, InMatch};
+use crate::ai::{Difficulty, OpponentAi};
+use crate::combat::DeclaredAttackers;
+use crate::gameplay::GameplayState;
+use crate::mcts::MctsSettings;
+use crate::rng::GameRng;
+use crate::turn::{player_has_priority, TurnPhase};
+
+// Pause between the opponent's actions so the player can follow them
+const OPPONENT_STEP_SECONDS: f32 = 0.8;
+
+// Plugin initializer for the computer opponent
+pub fn init_opponent_systems(app: &mut App) {
+    app.init_resource::<Difficulty>()
+        .add_systems(OnEnter(InMatch), setup_opponent)
+        .add_systems(
+            Update,
+            (
+                opponent_mulligan_system.run_if(in_state(TurnPhase::Mulligan)),
+                opponent_turn_system.run_if(not(player_has_priority)),
+            )
+                .run_if(in_state(GameState::Playing)),
+        );
+}
+
+// A decision being worked out on a worker thread; the AI travels with it and comes back
+type Thinking = Task<(Box<dyn OpponentAi>, Action)>;
+
+// The AI playing the opponent's side this match
+#[derive(Resource)]
+pub struct OpponentBrain {
+    // None while the AI is off thinking
+    pub ai: Option<Box<dyn OpponentAi>>,
+    pub thinking: Option<Thinking>,
+    pub step_timer: Timer,
+}
+
+// Create this match's opponent from the chosen difficulty
+pub fn setup_opponent(
+    mut commands: Commands,
+    difficulty: Res<Difficulty>,
+    mcts_settings: Res<MctsSettings>,
+) {
+    commands.insert_resource(OpponentBrain {
+        ai: Some(difficulty.opponent(&mcts_settings)),
+        thinking: None,
+        step_timer: Timer::from_seconds(OPPONENT_STEP_SECONDS, TimerMode::Once),
+    });
+}
+
+// Let the AI keep or redraw the opponent's opening hand
+pub fn opponent_mulligan_system(
+    mut brain: ResMut<OpponentBrain>,
+    mut gameplay_state: ResMut<GameplayState>,
+    mut rng: ResMut<GameRng>,
+) {
+    if gameplay_state.opponent.mulligan_done {
+        return;
+    }
+    let Some(ai) = brain.ai.as_mut() else {
+        return;
+    };
+
+    let picks = ai.choose_mulligan(&gameplay_state, PlayerSide::Opponent);
+    if let Err(error) = gameplay_state.mulligan(PlayerSide::Opponent, &picks, &mut *rng) {
+        warn!("Opponent couldn't shuffle back {:?}: {}; keeping its hand", picks, error);
+        let _ = gameplay_state.mulligan(PlayerSide::Opponent, &[], &mut *rng);
+    }
+}
+
+// Drive the opponent one step at a time whenever it has priority: on its own turn, ask the AI
+// for actions until it attacks, then discard down to the hand limit at the end of the turn;
+// with something on the stack, ask the AI for a response or a pass. Each decision runs on the
+// async compute pool so a long search never stalls a frame.
+pub fn opponent_turn_system(
+    mut commands: Commands,
+    mut brain: ResMut<OpponentBrain>,
+    mut gameplay_state: ResMut<GameplayState>,
+    mut rng: ResMut<GameRng>,
+    phase: Res<State<TurnPhase>>,
+    mut next_phase: ResMut<NextState<TurnPhase>>,
+    time: Res<Time>,
+) {
+    brain.step_timer.tick(time.delta());
+
+    // Carry out a decision as soon as it's ready
+    if let Some(thinking) = &mut brain.thinking {
+        let Some((ai, action)) = check_ready(thinking) else {
+            return;
+        };
+        brain.thinking = None;
+        brain.ai = Some(ai);
+        brain.step_timer.reset();
+
+        match action {
+            // The card's entity follows the rules state into its slot, turning face up
+            Action::Play { hand_index, slot, target } => {
+                if let Err(error) = gameplay_state.play(hand_index, slot, target) {
+                    warn!("Opponent tried an illegal play: card {} into slot {}: {}", hand_index, slot, error);
+                }
+            }
+            Action::Activate { slot, ability, target } => {
+                if let Err(error) = gameplay_state.activate(slot, ability, target) {
+                    warn!("Opponent tried an illegal activation: ability {} of slot {}: {}", ability, slot, error);
+                }
+            }
+            Action::Attack { slots } => {
+                commands.insert_resource(DeclaredAttackers { slots });
+                next_phase.set(TurnPhase::Combat);
+            }
+            Action::Pass => {
+                if let Err(error) = gameplay_state.pass() {
+                    warn!("Opponent couldn't pass: {}", error);
+                }
+            }
+        }
+        return;
+    }
+
+    if !brain.step_timer.is_finished() {
+        return;
+    }
+
+    // Something on the stack waits for an answer whatever the phase; otherwise the AI only
+    // acts in its main phase
+    if gameplay_state.stack.is_empty() {
+        match phase.get() {
+            TurnPhase::End if gameplay_state.hand_overflow(PlayerSide::Opponent) > 0 => {
+                let Some(ai) = brain.ai.as_mut() else {
+                    return;
+                };
+                let hand_index = ai.choose_discard(&gameplay_state, PlayerSide::Opponent);
+                if let Err(error) = gameplay_state.discard(PlayerSide::Opponent, hand_index) {
+                    warn!("Opponent couldn't discard card {}: {}", hand_index, error);
+                }
+                brain.step_timer.reset();
+                return;
+            }
+            TurnPhase::Main => {}
+            _ => return,
+        }
+    }
+
+    let Some(mut ai) = brain.ai.take() else {
+        return;
+    };
+    // The AI thinks about its own copy of the match
+    let state = gameplay_state.0.clone();
+    // The worker gets its own generator, seeded from the match's, so games still replay
+    let mut worker_rng = StdRng::seed_from_u64(rng.next_u64());
+    brain.thinking = Some(AsyncComputeTaskPool::get().spawn(async move {
+        let action = ai.choose_action(&state, &mut worker_rng);
+        (ai, action)
+    }));
+}
@@ -1,5 +1,7 @@
 use bevy::prelude::*;
+use cardigan_core::MulliganRule;
 use crate::GameState;
+use crate::gameplay::{GameMode, MatchRulesConfig};
 
 // Plugin initializer for options systems
 pub fn init_options_systems(app: &mut App) {
@@ -7,7 +9,13 @@ pub fn init_options_systems(app: &mut App) {
         .add_systems(OnExit(GameState::Options), cleanup_options)
         .add_systems(
             Update,
-            (options_button_system, options_button_interaction)
+            (
+                options_button_system,
+                options_button_interaction,
+                mode_label_system,
+                deck_out_label_system,
+                mulligan_label_system,
+            )
                 .run_if(in_state(GameState::Options)),
         );
 }
@@ -19,9 +27,24 @@ pub struct OptionsEntity;
 // Component for options buttons
 #[derive(Component)]
 pub enum OptionsButton {
+    Mode,
+    DeckOut,
+    Mulligan,
     Back,
 }
 
+// Component to mark the game mode toggle's text
+#[derive(Component)]
+pub struct ModeLabel;
+
+// Component to mark the deck-out rule toggle's text
+#[derive(Component)]
+pub struct DeckOutLabel;
+
+// Component to mark the mulligan rule toggle's text
+#[derive(Component)]
+pub struct MulliganLabel;
+
 // Setup options UI
 pub fn setup_options(mut commands: Commands) {
     // Root node for the options menu
@@ -52,19 +75,92 @@ pub fn setup_options(mut commands: Commands) {
                 },
             ));
 
-            // Placeholder text for future options
-            parent.spawn((
-                Text::new("Settings coming soon..."),
-                TextFont {
-                    font_size: 30.0,
-                    ..default()
-                },
-                TextColor(Color::srgb(0.7, 0.7, 0.75)),
-                Node {
-                    margin: UiRect::bottom(Val::Px(60.0)),
-                    ..default()
-                },
-            ));
+            // Game mode toggle
+            parent
+                .spawn((
+                    Button,
+                    Node {
+                        width: Val::Px(420.0),
+                        height: Val::Px(65.0),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        margin: UiRect::all(Val::Px(10.0)),
+                        border: UiRect::all(Val::Px(2.0)),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgb(0.15, 0.15, 0.2)),
+                    BorderColor::from(Color::srgb(0.4, 0.4, 0.5)),
+                    OptionsButton::Mode,
+                ))
+                .with_children(|parent| {
+                    parent.spawn((
+                        Text::new(""),
+                        TextFont {
+                            font_size: 30.0,
+                            ..default()
+                        },
+                        TextColor(Color::srgb(0.9, 0.9, 0.95)),
+                        ModeLabel,
+                    ));
+                });
+
+            // Deck-out rule toggle
+            parent
+                .spawn((
+                    Button,
+                    Node {
+                        width: Val::Px(420.0),
+                        height: Val::Px(65.0),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        margin: UiRect::all(Val::Px(10.0)),
+                        border: UiRect::all(Val::Px(2.0)),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgb(0.15, 0.15, 0.2)),
+                    BorderColor::from(Color::srgb(0.4, 0.4, 0.5)),
+                    OptionsButton::DeckOut,
+                ))
+                .with_children(|parent| {
+                    parent.spawn((
+                        Text::new(""),
+                        TextFont {
+                            font_size: 30.0,
+                            ..default()
+                        },
+                        TextColor(Color::srgb(0.9, 0.9, 0.95)),
+                        DeckOutLabel,
+                    ));
+                });
+
+            // Mulligan rule toggle
+            parent
+                .spawn((
+                    Button,
+                    Node {
+                        width: Val::Px(420.0),
+                        height: Val::Px(65.0),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        margin: UiRect::all(Val::Px(10.0)),
+                        border: UiRect::all(Val::Px(2.0)),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgb(0.15, 0.15, 0.2)),
+                    BorderColor::from(Color::srgb(0.4, 0.4, 0.5)),
+                    OptionsButton::Mulligan,
+                ))
+                .with_children(|parent| {
+                    parent.spawn((
+                        Text::new(""),
+                        TextFont {
+                            font_size: 30.0,
+                            ..default()
+                        },
+                        TextColor(Color::srgb(0.9, 0.9, 0.95)),
+                        MulliganLabel,
+                    ));
+                });
 
             // Back button
             parent
@@ -132,10 +228,27 @@ pub fn options_button_interaction(
 pub fn options_button_system(
     interaction_query: Query<(&Interaction, &OptionsButton), (Changed<Interaction>, With<Button>)>,
     mut next_state: ResMut<NextState<GameState>>,
+    mut match_rules: ResMut<MatchRulesConfig>,
+    mut game_mode: ResMut<GameMode>,
 ) {
     for (interaction, button) in interaction_query.iter() {
         if *interaction == Interaction::Pressed {
             match button {
+                OptionsButton::Mode => {
+                    *game_mode = match *game_mode {
+                        GameMode::Standard => GameMode::Battleline,
+                        GameMode::Battleline => GameMode::Standard,
+                    };
+                }
+                OptionsButton::DeckOut => {
+                    match_rules.deck_out_loses = !match_rules.deck_out_loses;
+                }
+                OptionsButton::Mulligan => {
+                    match_rules.mulligan = match match_rules.mulligan {
+                        MulliganRule::Partial => MulliganRule::Full,
+                        MulliganRule::Full => MulliganRule::Partial,
+                    };
+                }
                 OptionsButton::Back => {
                     next_state.set(GameState::Menu);
                 }
@@ -143,3 +256,53 @@ pub fn options_button_system(
         }
     }
 }
+
+// Keep the game mode toggle text in sync with the chosen mode and its board
+pub fn mode_label_system(
+    mut label_query: Query<&mut Text, With<ModeLabel>>,
+    game_mode: Res<GameMode>,
+) {
+    let play_area = game_mode.play_area();
+    let value = format!("MODE: {} ({}x{})", game_mode.label(), play_area.rows, play_area.slots_per_row);
+
+    for mut text in label_query.iter_mut() {
+        if text.0 != value {
+            text.0 = value.clone();
+        }
+    }
+}
+
+// Keep the deck-out toggle text in sync with the match rules
+pub fn deck_out_label_system(
+    mut label_query: Query<&mut Text, With<DeckOutLabel>>,
+    match_rules: Res<MatchRulesConfig>,
+) {
+    let value = if match_rules.deck_out_loses {
+        "DECKING OUT LOSES: ON"
+    } else {
+        "DECKING OUT LOSES: OFF"
+    };
+
+    for mut text in label_query.iter_mut() {
+        if text.0 != value {
+            text.0 = value.to_string();
+        }
+    }
+}
+
+// Keep the mulligan toggle text in sync with the match rules
+pub fn mulligan_label_system(
+    mut label_query: Query<&mut Text, With<MulliganLabel>>,
+    match_rules: Res<MatchRulesConfig>,
+) {
+    let value = match match_rules.mulligan {
+        MulliganRule::Partial => "MULLIGAN: PICK CARDS",
+        MulliganRule::Full => "MULLIGAN: WHOLE HAND",
+    };
+
+    for mut text in label_query.iter_mut() {
+        if text.0 != value {
+            text.0 = value.to_string();
+        }
+    }
+}
@@ -1,18 +1,21 @@
 use bevy::prelude::*;
-use crate::GameState;
-use crate::gameplay::GameEntity;
+use crate::{GameState, InMatch};
+use crate::rng::GameRng;
+use crate::targeting::Targeting;
 
 // Plugin initializer for pause systems
 pub fn init_pause_systems(app: &mut App) {
     app.add_systems(OnEnter(GameState::Paused), setup_pause_menu)
+        .add_systems(OnExit(GameState::Paused), cleanup_pause_menu)
         .add_systems(
-            OnExit(GameState::Paused),
-            (cleanup_pause_menu, cleanup_game_on_menu_return),
+            Update,
+            (pause_button_system, pause_button_interaction)
+                .run_if(in_state(GameState::Paused)),
         )
+        // While choosing a target, Escape cancels the choice instead
         .add_systems(
             Update,
-            (pause_button_system, pause_button_interaction, handle_pause_input)
-                .run_if(in_state(GameState::Paused)),
+            handle_pause_input.run_if(in_state(InMatch).and(not(resource_exists::<Targeting>))),
         );
 }
 
@@ -43,7 +46,7 @@ pub fn handle_pause_input(
 }
 
 // Setup pause menu UI
-pub fn setup_pause_menu(mut commands: Commands) {
+pub fn setup_pause_menu(mut commands: Commands, rng: Res<GameRng>) {
     // Root node for the pause menu
     commands
         .spawn((
@@ -68,7 +71,21 @@ pub fn setup_pause_menu(mut commands: Commands) {
                 },
                 TextColor(Color::srgb(0.9, 0.9, 0.95)),
                 Node {
-                    margin: UiRect::bottom(Val::Px(80.0)),
+                    margin: UiRect::bottom(Val::Px(20.0)),
+                    ..default()
+                },
+            ));
+
+            // Match seed, so a bug report can replay the exact same game
+            parent.spawn((
+                Text::new(format!("SEED: {}", rng.seed())),
+                TextFont {
+                    font_size: 24.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.7, 0.7, 0.75)),
+                Node {
+                    margin: UiRect::bottom(Val::Px(60.0)),
                     ..default()
                 },
             ));
@@ -138,22 +155,6 @@ pub fn cleanup_pause_menu(mut commands: Commands, pause_entities: Query<Entity,
     }
 }
 
-// Cleanup game entities when returning to menu from pause
-pub fn cleanup_game_on_menu_return(
-    mut commands: Commands,
-    game_entities: Query<Entity, With<GameEntity>>,
-    next_state: Option<Res<NextState<GameState>>>,
-) {
-    // Only cleanup if we're transitioning to Menu
-    if let Some(next) = next_state {
-        if matches!(next.as_ref(), NextState::Pending(GameState::Menu)) {
-            for entity in game_entities.iter() {
-                commands.entity(entity).despawn();
-            }
-        }
-    }
-}
-
 // Handle pause button interactions (hover effects)
 pub fn pause_button_interaction(
     mut interaction_query: Query<
@@ -0,0 +1,26 @@
+use bevy::prelude::*;
+
+// Whether a world-space point is on a sprite, following the entity's rotation and scale, so a
+// card fanned out in the hand is only picked inside its own tilted outline
+pub fn sprite_contains(transform: &Transform, sprite: &Sprite, point: Vec2) -> bool {
+    let Some(size) = sprite.custom_size else {
+        return false;
+    };
+
+    // In the sprite's own frame its bounds are axis-aligned again
+    let local = transform.rotation.inverse() * (point.extend(transform.translation.z) - transform.translation);
+    let half_size = size * transform.scale.truncate() / 2.0;
+    local.x.abs() <= half_size.x && local.y.abs() <= half_size.y
+}
+
+// Of the candidates whose sprite is under the point, the one drawn in front
+pub fn topmost_at<'a, T>(
+    point: Vec2,
+    candidates: impl IntoIterator<Item = (T, &'a Transform, &'a Sprite)>,
+) -> Option<T> {
+    candidates
+        .into_iter()
+        .filter(|(_, transform, sprite)| sprite_contains(transform, sprite, point))
+        .max_by(|(_, a, _), (_, b, _)| a.translation.z.total_cmp(&b.translation.z))
+        .map(|(picked, _, _)| picked)
+}
@@ -0,0 +1,461 @@
+use bevy::prelude::*;
+use bevy::input::mouse::{MouseScrollUnit, MouseWheel};
+use bevy::ui::FocusPolicy;
+use cardigan_core::{PileKind, PlayerSide};
+use crate::{GameState, InMatch};
+use crate::gameplay::{AnchorPosition, CardZone, GameEntity, GameplayState, LayoutZones};
+use crate::picking::topmost_at;
+use crate::startup::VirtualResolution;
+use crate::targeting::Targeting;
+
+// Piles are drawn at this fraction of a card's size so they fit in the corners
+const PILE_SCALE: f32 = 0.5;
+
+// Most card layers shown in a pile's stack, however many cards it holds
+const MAX_STACK_LAYERS: usize = 3;
+
+// How far apart stacked layers are drawn
+const STACK_LAYER_OFFSET: f32 = 4.0;
+
+// Pixels the pile browser scrolls per mouse wheel line
+const SCROLL_LINE_HEIGHT: f32 = 30.0;
+
+// Plugin initializer for graveyard and exile piles
+pub fn init_pile_systems(app: &mut App) {
+    app.add_systems(OnEnter(InMatch), setup_piles)
+        .add_systems(
+            Update,
+            (
+                pile_display_system,
+                pile_click_system.run_if(pile_browser_closed.and(not(resource_exists::<Targeting>))),
+                pile_browser_button_system,
+                pile_browser_button_interaction,
+                pile_browser_scroll_system,
+            )
+                .run_if(in_state(GameState::Playing)),
+        );
+}
+
+// Title shown on a pile and its browser
+pub fn pile_label(kind: PileKind) -> &'static str {
+    match kind {
+        PileKind::Graveyard => "GRAVEYARD",
+        PileKind::Exile => "EXILE",
+    }
+}
+
+// Component to mark a pile on the board; its CardZone says whose pile it is
+#[derive(Component)]
+pub struct Pile;
+
+// Component for one card layer of a pile's stack, `depth` 0 being the bottom
+#[derive(Component)]
+pub struct PileLayer {
+    pub depth: usize,
+}
+
+// Component to mark a pile's name and count text
+#[derive(Component)]
+pub struct PileLabel;
+
+// Component to mark the pile browser overlay
+#[derive(Component)]
+pub struct PileBrowser;
+
+// Component to mark the scrolling list inside the pile browser
+#[derive(Component)]
+pub struct PileBrowserList;
+
+// Component for pile browser buttons
+#[derive(Component)]
+pub enum PileBrowserButton {
+    Close,
+}
+
+// Run condition: no pile browser is covering the board
+pub fn pile_browser_closed(browser_query: Query<(), With<PileBrowser>>) -> bool {
+    browser_query.is_empty()
+}
+
+// The corner zone a pile lives in. Player piles sit along the bottom edge (exile next to
+// the deck) and the opponent's mirror them along the top.
+pub fn pile_zone(side: PlayerSide, kind: PileKind) -> CardZone {
+    match (side, kind) {
+        (PlayerSide::Player, PileKind::Graveyard) => CardZone::BottomLeft,
+        (PlayerSide::Player, PileKind::Exile) => CardZone::BottomRight,
+        (PlayerSide::Opponent, PileKind::Graveyard) => CardZone::TopRight,
+        (PlayerSide::Opponent, PileKind::Exile) => CardZone::TopLeft,
+    }
+}
+
+// Whose pile a corner zone holds
+pub fn zone_pile(zone: &CardZone) -> Option<(PlayerSide, PileKind)> {
+    match zone {
+        CardZone::BottomLeft => Some((PlayerSide::Player, PileKind::Graveyard)),
+        CardZone::BottomRight => Some((PlayerSide::Player, PileKind::Exile)),
+        CardZone::TopRight => Some((PlayerSide::Opponent, PileKind::Graveyard)),
+        CardZone::TopLeft => Some((PlayerSide::Opponent, PileKind::Exile)),
+        _ => None,
+    }
+}
+
+// Setup a graveyard and an exile pile for each player
+pub fn setup_piles(
+    mut commands: Commands,
+    resolution: Res<VirtualResolution>,
+) {
+    let layout = LayoutZones::new(&resolution);
+    let pile_size = layout.card_size * PILE_SCALE;
+    let margin = layout.card_width * 0.1;
+
+    // Offsets from the corner to the pile's center; exile piles leave room for the deck
+    let corner_x = pile_size.x / 2.0 + margin;
+    let beside_deck_x = layout.card_width + margin * 2.0 + pile_size.x / 2.0;
+    let corner_y = pile_size.y / 2.0 + margin;
+
+    for side in [PlayerSide::Player, PlayerSide::Opponent] {
+        for kind in [PileKind::Graveyard, PileKind::Exile] {
+            let anchor = match (side, kind) {
+                (PlayerSide::Player, PileKind::Graveyard) => AnchorPosition::BottomLeft {
+                    offset_x: corner_x,
+                    offset_y: corner_y,
+                },
+                (PlayerSide::Player, PileKind::Exile) => AnchorPosition::BottomRight {
+                    offset_x: -beside_deck_x,
+                    offset_y: corner_y,
+                },
+                (PlayerSide::Opponent, PileKind::Graveyard) => AnchorPosition::TopRight {
+                    offset_x: -corner_x,
+                    offset_y: -corner_y,
+                },
+                (PlayerSide::Opponent, PileKind::Exile) => AnchorPosition::TopLeft {
+                    offset_x: beside_deck_x,
+                    offset_y: -corner_y,
+                },
+            };
+            let position = anchor.resolve(&resolution);
+
+            let layer_color = match kind {
+                PileKind::Graveyard => Color::srgb(0.45, 0.42, 0.4),
+                PileKind::Exile => Color::srgb(0.45, 0.38, 0.6),
+            };
+
+            commands
+                .spawn((
+                    Pile,
+                    pile_zone(side, kind),
+                    anchor,
+                    Sprite {
+                        color: Color::srgba(0.3, 0.3, 0.4, 0.3),  // Faint outline when empty
+                        custom_size: Some(pile_size),
+                        ..default()
+                    },
+                    Transform::from_xyz(position.x, position.y, 0.0),
+                    GameEntity,
+                ))
+                .with_children(|parent| {
+                    // Card layers, shown as the pile fills up
+                    for depth in 0..MAX_STACK_LAYERS {
+                        let offset = depth as f32 * STACK_LAYER_OFFSET;
+                        parent
+                            .spawn((
+                                Sprite {
+                                    color: Color::srgb(0.25, 0.25, 0.3),  // Layer border
+                                    custom_size: Some(pile_size + Vec2::splat(4.0)),
+                                    ..default()
+                                },
+                                Transform::from_xyz(offset, offset, 0.1 + depth as f32 * 0.1),
+                                Visibility::Hidden,
+                                PileLayer { depth },
+                            ))
+                            .with_children(|parent| {
+                                parent.spawn((
+                                    Sprite {
+                                        color: layer_color,
+                                        custom_size: Some(pile_size),
+                                        ..default()
+                                    },
+                                    Transform::from_xyz(0.0, 0.0, 0.01),
+                                ));
+                            });
+                    }
+
+                    // Pile name and card count
+                    parent.spawn((
+                        Text2d::new(pile_label(kind)),
+                        TextFont {
+                            font_size: 18.0,
+                            ..default()
+                        },
+                        TextColor(Color::srgb(0.85, 0.85, 0.9)),
+                        TextLayout::new_with_justify(Justify::Center),
+                        Transform::from_xyz(0.0, 0.0, 1.0),
+                        PileLabel,
+                    ));
+                });
+        }
+    }
+}
+
+// Keep each pile's stack height and count in sync with the gameplay state
+pub fn pile_display_system(
+    pile_query: Query<(&CardZone, &Children), With<Pile>>,
+    mut layer_query: Query<(&PileLayer, &mut Visibility)>,
+    mut label_query: Query<&mut Text2d, With<PileLabel>>,
+    gameplay_state: Res<GameplayState>,
+) {
+    if !gameplay_state.is_changed() {
+        return;
+    }
+
+    for (zone, children) in pile_query.iter() {
+        let Some((side, kind)) = zone_pile(zone) else {
+            continue;
+        };
+        let count = gameplay_state.side(side).pile(kind).len();
+
+        for child in children.iter() {
+            if let Ok((layer, mut visibility)) = layer_query.get_mut(child) {
+                *visibility = if layer.depth < count.min(MAX_STACK_LAYERS) {
+                    Visibility::Inherited
+                } else {
+                    Visibility::Hidden
+                };
+            }
+
+            if let Ok(mut text) = label_query.get_mut(child) {
+                let value = format!("{}\n{}", pile_label(kind), count);
+                if text.0 != value {
+                    text.0 = value;
+                }
+            }
+        }
+    }
+}
+
+// Clicking a pile opens a browsable list of its cards
+pub fn pile_click_system(
+    mut commands: Commands,
+    pile_query: Query<(&CardZone, &Transform, &Sprite), With<Pile>>,
+    gameplay_state: Res<GameplayState>,
+    mouse_button: Res<ButtonInput<MouseButton>>,
+    window_query: Query<&Window>,
+    resolution: Res<VirtualResolution>,
+) {
+    if !mouse_button.just_pressed(MouseButton::Left) {
+        return;
+    }
+
+    let Some(window) = window_query.iter().next() else {
+        return;
+    };
+
+    // Get cursor position in world space
+    let Some(cursor_world_pos) = resolution.cursor_position(window) else {
+        return;
+    };
+
+    let clicked_pile = topmost_at(cursor_world_pos, pile_query.iter());
+    if let Some((side, kind)) = clicked_pile.and_then(zone_pile) {
+        spawn_pile_browser(&mut commands, &gameplay_state, side, kind);
+    }
+}
+
+// Spawn the overlay listing a pile's cards, most recent first
+fn spawn_pile_browser(
+    commands: &mut Commands,
+    gameplay_state: &GameplayState,
+    side: PlayerSide,
+    kind: PileKind,
+) {
+    let cards = gameplay_state.side(side).pile(kind);
+    let owner = match side {
+        PlayerSide::Player => "YOUR",
+        PlayerSide::Opponent => "OPPONENT'S",
+    };
+
+    // Full-screen root that dims the board and blocks clicks to the UI underneath
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                position_type: PositionType::Absolute,
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.6)),
+            FocusPolicy::Block,
+            GlobalZIndex(10),
+            PileBrowser,
+            GameEntity,
+        ))
+        .with_children(|parent| {
+            // Panel
+            parent
+                .spawn((
+                    Node {
+                        width: Val::Px(460.0),
+                        height: Val::Percent(75.0),
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Center,
+                        padding: UiRect::all(Val::Px(20.0)),
+                        border: UiRect::all(Val::Px(2.0)),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgb(0.12, 0.12, 0.17)),
+                    BorderColor::from(Color::srgb(0.4, 0.4, 0.5)),
+                ))
+                .with_children(|parent| {
+                    // Title
+                    parent.spawn((
+                        Text::new(format!("{} {} ({})", owner, pile_label(kind), cards.len())),
+                        TextFont {
+                            font_size: 30.0,
+                            ..default()
+                        },
+                        TextColor(Color::srgb(0.9, 0.9, 0.95)),
+                        Node {
+                            margin: UiRect::bottom(Val::Px(15.0)),
+                            ..default()
+                        },
+                    ));
+
+                    // Scrolling card list
+                    parent
+                        .spawn((
+                            Node {
+                                width: Val::Percent(100.0),
+                                flex_grow: 1.0,
+                                flex_direction: FlexDirection::Column,
+                                overflow: Overflow::scroll_y(),
+                                ..default()
+                            },
+                            ScrollPosition::default(),
+                            PileBrowserList,
+                        ))
+                        .with_children(|parent| {
+                            if cards.is_empty() {
+                                parent.spawn((
+                                    Text::new("No cards"),
+                                    TextFont {
+                                        font_size: 22.0,
+                                        ..default()
+                                    },
+                                    TextColor(Color::srgb(0.6, 0.6, 0.65)),
+                                ));
+                            }
+
+                            for card in cards.iter().rev() {
+                                let stats = match card.data.stats {
+                                    Some(stats) => format!("{}/{}", stats.attack, stats.health),
+                                    None => "spell".to_string(),
+                                };
+                                parent.spawn((
+                                    Text::new(format!("({}) {} - {}", card.data.cost, card.data.name, stats)),
+                                    TextFont {
+                                        font_size: 22.0,
+                                        ..default()
+                                    },
+                                    TextColor(Color::srgb(0.85, 0.85, 0.9)),
+                                    Node {
+                                        margin: UiRect::vertical(Val::Px(4.0)),
+                                        flex_shrink: 0.0,
+                                        ..default()
+                                    },
+                                ));
+                            }
+                        });
+
+                    // Close button
+                    parent
+                        .spawn((
+                            Button,
+                            Node {
+                                width: Val::Px(180.0),
+                                height: Val::Px(50.0),
+                                justify_content: JustifyContent::Center,
+                                align_items: AlignItems::Center,
+                                margin: UiRect::top(Val::Px(15.0)),
+                                border: UiRect::all(Val::Px(2.0)),
+                                ..default()
+                            },
+                            BackgroundColor(Color::srgb(0.15, 0.15, 0.2)),
+                            BorderColor::from(Color::srgb(0.4, 0.4, 0.5)),
+                            PileBrowserButton::Close,
+                        ))
+                        .with_children(|parent| {
+                            parent.spawn((
+                                Text::new("CLOSE"),
+                                TextFont {
+                                    font_size: 28.0,
+                                    ..default()
+                                },
+                                TextColor(Color::srgb(0.9, 0.9, 0.95)),
+                            ));
+                        });
+                });
+        });
+}
+
+// Handle pile browser button clicks
+pub fn pile_browser_button_system(
+    mut commands: Commands,
+    interaction_query: Query<(&Interaction, &PileBrowserButton), (Changed<Interaction>, With<Button>)>,
+    browser_query: Query<Entity, With<PileBrowser>>,
+) {
+    for (interaction, button) in interaction_query.iter() {
+        if *interaction == Interaction::Pressed {
+            match button {
+                PileBrowserButton::Close => {
+                    for entity in browser_query.iter() {
+                        commands.entity(entity).despawn();
+                    }
+                }
+            }
+        }
+    }
+}
+
+// Handle pile browser button interactions (hover effects)
+pub fn pile_browser_button_interaction(
+    mut interaction_query: Query<
+        (&Interaction, &mut BackgroundColor, &mut BorderColor),
+        (Changed<Interaction>, With<PileBrowserButton>),
+    >,
+) {
+    for (interaction, mut bg_color, mut border_color) in interaction_query.iter_mut() {
+        match *interaction {
+            Interaction::Pressed => {
+                *bg_color = BackgroundColor(Color::srgb(0.25, 0.25, 0.3));
+                *border_color = BorderColor::from(Color::srgb(0.6, 0.6, 0.7));
+            }
+            Interaction::Hovered => {
+                *bg_color = BackgroundColor(Color::srgb(0.2, 0.2, 0.25));
+                *border_color = BorderColor::from(Color::srgb(0.7, 0.7, 0.8));
+            }
+            Interaction::None => {
+                *bg_color = BackgroundColor(Color::srgb(0.15, 0.15, 0.2));
+                *border_color = BorderColor::from(Color::srgb(0.4, 0.4, 0.5));
+            }
+        }
+    }
+}
+
+// Scroll the pile browser list with the mouse wheel
+pub fn pile_browser_scroll_system(
+    mut wheel_events: MessageReader<MouseWheel>,
+    mut list_query: Query<&mut ScrollPosition, With<PileBrowserList>>,
+) {
+    for event in wheel_events.read() {
+        let delta = match event.unit {
+            MouseScrollUnit::Line => event.y * SCROLL_LINE_HEIGHT,
+            MouseScrollUnit::Pixel => event.y,
+        };
+
+        for mut scroll in list_query.iter_mut() {
+            scroll.y = (scroll.y - delta).max(0.0);
+        }
+    }
+}
@@ -0,0 +1,71 @@
+use bevy::prelude::*;
+use rand::rngs::StdRng;
+use rand::{RngCore, SeedableRng};
+
+// Environment variable that fixes the match seed, e.g. `CARDIGAN_SEED=1234 cargo run`
+pub const SEED_ENV_VAR: &str = "CARDIGAN_SEED";
+
+// Plugin initializer for the random number generator
+pub fn init_rng_systems(app: &mut App) {
+    app.insert_resource(SeedConfig::from_env());
+}
+
+// Where match seeds come from: a fixed seed replays the same game every time
+#[derive(Resource, Default)]
+pub struct SeedConfig {
+    pub fixed_seed: Option<u64>,
+}
+
+impl SeedConfig {
+    pub fn from_env() -> Self {
+        let fixed_seed = std::env::var(SEED_ENV_VAR).ok().and_then(|value| {
+            let seed = value.trim().parse::<u64>().ok();
+            if seed.is_none() {
+                warn!("Ignoring {}={:?}: expected an unsigned integer", SEED_ENV_VAR, value);
+            }
+            seed
+        });
+
+        Self { fixed_seed }
+    }
+
+    // Seed for the next match
+    pub fn next_seed(&self) -> u64 {
+        self.fixed_seed.unwrap_or_else(rand::random)
+    }
+}
+
+// The single source of randomness for a match. Every random decision (shuffles,
+// card colors, AI choices) must draw from this so a seed reproduces the whole game.
+#[derive(Resource)]
+pub struct GameRng {
+    seed: u64,
+    rng: StdRng,
+}
+
+impl GameRng {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+}
+
+impl RngCore for GameRng {
+    fn next_u32(&mut self) -> u32 {
+        self.rng.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.rng.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        self.rng.fill_bytes(dst)
+    }
+}
@@ -0,0 +1,95 @@
+use bevy::prelude::*;
+use crate::{GameState, InMatch};
+use crate::gameplay::{GameEntity, GameplayState};
+
+// How long a script error stays on screen
+const SCRIPT_ERROR_SECONDS: f32 = 8.0;
+
+// Plugin initializer for showing card script errors during a match
+pub fn init_scripting_systems(app: &mut App) {
+    app.add_systems(OnEnter(InMatch), setup_script_error_panel)
+        .add_systems(
+            Update,
+            (script_error_system, script_error_expiry_system)
+                .run_if(in_state(GameState::Playing)),
+        );
+}
+
+// Component to mark the column script errors are listed in
+#[derive(Component)]
+pub struct ScriptErrorPanel;
+
+// Component for one script error message, removed when its timer runs out
+#[derive(Component)]
+pub struct ScriptErrorMessage {
+    pub timer: Timer,
+}
+
+// Setup the (empty) error column across the top of the screen
+pub fn setup_script_error_panel(mut commands: Commands) {
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(60.0),
+            width: Val::Percent(100.0),
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Center,
+            row_gap: Val::Px(6.0),
+            ..default()
+        },
+        ScriptErrorPanel,
+        GameEntity,
+    ));
+}
+
+// Move errors from failed script effects out of the match state and onto the screen
+pub fn script_error_system(
+    mut commands: Commands,
+    mut gameplay_state: ResMut<GameplayState>,
+    panel_query: Query<Entity, With<ScriptErrorPanel>>,
+) {
+    if gameplay_state.script_errors.is_empty() {
+        return;
+    }
+
+    let errors = std::mem::take(&mut gameplay_state.script_errors);
+    let Ok(panel) = panel_query.single() else {
+        return;
+    };
+    for error in errors {
+        warn!("Card script failed: {}", error);
+        commands.entity(panel).with_children(|parent| {
+            parent.spawn((
+                Text::new(format!("Script error in {}", error)),
+                TextFont {
+                    font_size: 20.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(1.0, 0.75, 0.75)),
+                Node {
+                    padding: UiRect::axes(Val::Px(12.0), Val::Px(6.0)),
+                    max_width: Val::Percent(80.0),
+                    ..default()
+                },
+                BackgroundColor(Color::srgba(0.4, 0.05, 0.05, 0.85)),
+                ScriptErrorMessage {
+                    timer: Timer::from_seconds(SCRIPT_ERROR_SECONDS, TimerMode::Once),
+                },
+            ));
+        });
+    }
+}
+
+// Remove script errors that have been shown long enough
+pub fn script_error_expiry_system(
+    mut commands: Commands,
+    mut message_query: Query<(Entity, &mut ScriptErrorMessage)>,
+    time: Res<Time>,
+) {
+    for (entity, mut message) in message_query.iter_mut() {
+        message.timer.tick(time.delta());
+        if message.timer.is_finished() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
@@ -0,0 +1,282 @@
+use bevy::prelude::*;
+use cardigan_core::{PlayerSide, StackItem, StackKind, TargetChoice, Trigger};
+use crate::{GameState, InMatch};
+use crate::gameplay::{GameEntity, GameplayState};
+use crate::targeting::Targeting;
+use crate::turn::player_has_priority;
+
+// How long the player gets to look at the stack before passing for them when they have no
+// possible response
+const AUTO_PASS_SECONDS: f32 = 0.6;
+
+// Plugin initializer for the stack panel and passing priority
+pub fn init_stack_systems(app: &mut App) {
+    app.add_systems(OnEnter(InMatch), setup_stack_panel)
+        .add_systems(
+            Update,
+            (
+                stack_panel_system,
+                pass_button_system.run_if(not(resource_exists::<Targeting>)),
+                pass_button_interaction,
+                auto_pass_system.run_if(player_has_priority.and(not(resource_exists::<Targeting>))),
+            )
+                .run_if(in_state(GameState::Playing)),
+        );
+}
+
+// Component to mark the stack panel, hidden while the stack is empty
+#[derive(Component)]
+pub struct StackPanel;
+
+// Component to mark the panel's title, which says who may respond
+#[derive(Component)]
+pub struct StackTitle;
+
+// Component to mark the list of stack entries, next to resolve first
+#[derive(Component)]
+pub struct StackList;
+
+// Component for the "Pass" button, shown while the player may respond
+#[derive(Component)]
+pub struct PassButton;
+
+// Setup the stack panel on the right edge, above the End Turn button
+pub fn setup_stack_panel(mut commands: Commands) {
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                right: Val::Px(20.0),
+                bottom: Val::Percent(50.0),
+                margin: UiRect::bottom(Val::Px(80.0)),
+                width: Val::Px(240.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Stretch,
+                padding: UiRect::all(Val::Px(10.0)),
+                row_gap: Val::Px(6.0),
+                border: UiRect::all(Val::Px(2.0)),
+                display: Display::None,
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.12, 0.12, 0.17, 0.9)),
+            BorderColor::from(Color::srgb(0.4, 0.4, 0.5)),
+            StackPanel,
+            GameEntity,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new(""),
+                TextFont {
+                    font_size: 20.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.9, 0.9, 0.95)),
+                StackTitle,
+            ));
+
+            parent.spawn((
+                Node {
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Val::Px(4.0),
+                    ..default()
+                },
+                StackList,
+            ));
+
+            // Pass button
+            parent
+                .spawn((
+                    Button,
+                    Node {
+                        height: Val::Px(40.0),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        border: UiRect::all(Val::Px(2.0)),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgb(0.15, 0.15, 0.2)),
+                    BorderColor::from(Color::srgb(0.4, 0.4, 0.5)),
+                    PassButton,
+                ))
+                .with_children(|parent| {
+                    parent.spawn((
+                        Text::new("PASS"),
+                        TextFont {
+                            font_size: 24.0,
+                            ..default()
+                        },
+                        TextColor(Color::srgb(0.9, 0.9, 0.95)),
+                    ));
+                });
+        });
+}
+
+// What a stack entry is, in words
+fn stack_kind_label(kind: StackKind) -> &'static str {
+    match kind {
+        StackKind::Spell => "Spell",
+        StackKind::Activated => "Ability",
+        StackKind::Triggered(Trigger::OnPlay) => "When played",
+        StackKind::Triggered(Trigger::OnDeath) => "When it dies",
+        StackKind::Triggered(Trigger::StartOfTurn) => "Start of turn",
+        StackKind::Triggered(Trigger::EndOfTurn) => "End of turn",
+    }
+}
+
+// What a stack entry is aimed at, in words
+fn stack_target_label(gameplay_state: &GameplayState, target: TargetChoice) -> String {
+    match target {
+        TargetChoice::Player(PlayerSide::Player) => "you".to_string(),
+        TargetChoice::Player(PlayerSide::Opponent) => "the opponent".to_string(),
+        TargetChoice::Creature { side, slot } => match gameplay_state.side(side).board.get(slot) {
+            Some(Some(unit)) => unit.card.data.name.clone(),
+            _ => "a creature that is gone".to_string(),
+        },
+    }
+}
+
+// One line per stack entry: the card's name, then what kind of effect it is and its target
+fn stack_entry_text(gameplay_state: &GameplayState, item: &StackItem) -> String {
+    let mut text = format!("{}\n{}", item.card.data.name, stack_kind_label(item.kind));
+    if let Some(target) = item.target {
+        text.push_str(" -> ");
+        text.push_str(&stack_target_label(gameplay_state, target));
+    }
+    text
+}
+
+// Rebuild the panel from the stack: shown only while something is pending, with the entry that
+// resolves next on top
+pub fn stack_panel_system(
+    mut commands: Commands,
+    gameplay_state: Res<GameplayState>,
+    mut panel_query: Query<&mut Node, With<StackPanel>>,
+    mut title_query: Query<&mut Text, With<StackTitle>>,
+    list_query: Query<Entity, With<StackList>>,
+) {
+    if !gameplay_state.is_changed() {
+        return;
+    }
+
+    let display = if gameplay_state.stack.is_empty() {
+        Display::None
+    } else {
+        Display::Flex
+    };
+    for mut node in panel_query.iter_mut() {
+        if node.display != display {
+            node.display = display;
+        }
+    }
+
+    let title = match gameplay_state.priority {
+        PlayerSide::Player => "STACK - your response",
+        PlayerSide::Opponent => "STACK - opponent to respond",
+    };
+    for mut text in title_query.iter_mut() {
+        if text.0 != title {
+            text.0 = title.to_string();
+        }
+    }
+
+    let Ok(list) = list_query.single() else {
+        return;
+    };
+    commands.entity(list).despawn_related::<Children>();
+    commands.entity(list).with_children(|parent| {
+        for (depth, item) in gameplay_state.stack.iter().rev().enumerate() {
+            let background = match item.controller {
+                PlayerSide::Player => Color::srgba(0.15, 0.25, 0.45, 0.9),
+                PlayerSide::Opponent => Color::srgba(0.45, 0.15, 0.15, 0.9),
+            };
+            // The entry that resolves next stands out
+            let border = if depth == 0 {
+                Color::srgb(1.0, 0.85, 0.3)
+            } else {
+                Color::srgb(0.3, 0.3, 0.4)
+            };
+            parent.spawn((
+                Text::new(stack_entry_text(&gameplay_state, item)),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.9, 0.9, 0.95)),
+                Node {
+                    padding: UiRect::axes(Val::Px(8.0), Val::Px(4.0)),
+                    border: UiRect::all(Val::Px(2.0)),
+                    ..default()
+                },
+                BackgroundColor(background),
+                BorderColor::from(border),
+            ));
+        }
+    });
+}
+
+// Handle Pass clicks; only the player with priority can pass
+pub fn pass_button_system(
+    interaction_query: Query<&Interaction, (Changed<Interaction>, With<PassButton>)>,
+    mut gameplay_state: ResMut<GameplayState>,
+) {
+    for interaction in interaction_query.iter() {
+        if *interaction == Interaction::Pressed
+            && gameplay_state.priority == PlayerSide::Player
+            && let Err(error) = gameplay_state.pass()
+        {
+            debug!("Can't pass: {}", error);
+        }
+    }
+}
+
+// Pass button colors; the button is only shown while the player may respond
+pub fn pass_button_interaction(
+    mut button_query: Query<(&Interaction, &mut Node, &mut BackgroundColor, &mut BorderColor), With<PassButton>>,
+    gameplay_state: Res<GameplayState>,
+) {
+    let shown = gameplay_state.priority == PlayerSide::Player && !gameplay_state.stack.is_empty();
+    let display = if shown { Display::Flex } else { Display::None };
+
+    for (interaction, mut node, mut bg_color, mut border_color) in button_query.iter_mut() {
+        if node.display != display {
+            node.display = display;
+        }
+
+        match *interaction {
+            Interaction::Pressed => {
+                *bg_color = BackgroundColor(Color::srgb(0.25, 0.25, 0.3));
+                *border_color = BorderColor::from(Color::srgb(0.6, 0.6, 0.7));
+            }
+            Interaction::Hovered => {
+                *bg_color = BackgroundColor(Color::srgb(0.2, 0.2, 0.25));
+                *border_color = BorderColor::from(Color::srgb(0.7, 0.7, 0.8));
+            }
+            Interaction::None => {
+                *bg_color = BackgroundColor(Color::srgb(0.15, 0.15, 0.2));
+                *border_color = BorderColor::from(Color::srgb(0.4, 0.4, 0.5));
+            }
+        }
+    }
+}
+
+// Pass for the player when they couldn't respond anyway, after a moment to read the stack
+pub fn auto_pass_system(
+    mut gameplay_state: ResMut<GameplayState>,
+    mut waited: Local<f32>,
+    time: Res<Time>,
+) {
+    let can_respond = !gameplay_state.legal_plays().is_empty() || !gameplay_state.legal_activations().is_empty();
+    if gameplay_state.stack.is_empty() || can_respond {
+        *waited = 0.0;
+        return;
+    }
+
+    *waited += time.delta_secs();
+    if *waited < AUTO_PASS_SECONDS {
+        return;
+    }
+    *waited = 0.0;
+    if let Err(error) = gameplay_state.pass() {
+        warn!("Couldn't pass for the player: {}", error);
+    }
+}
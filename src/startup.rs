@@ -1,19 +1,61 @@
 use bevy::prelude::*;
 
-const WINDOW_HEIGHT: f32 = 1600.0;
+// Height of the world the camera shows, in world units, whatever the window's size
+pub const VIRTUAL_HEIGHT: f32 = 1600.0;
 
 // Plugin initializer for startup systems
 pub fn init_startup_systems(app: &mut App) {
-    app.add_systems(Startup, setup_camera);
+    app.init_resource::<VirtualResolution>()
+        .add_systems(Startup, setup_camera);
 }
 
-// Setup camera (runs once at startup)
-pub fn setup_camera(mut commands: Commands) {
+// The world the camera shows: always VIRTUAL_HEIGHT tall, and as wide as the window's aspect
+// ratio makes it. Layout and input both work in these units, so they agree at any window size.
+#[derive(Resource, Clone, PartialEq)]
+pub struct VirtualResolution {
+    pub width: f32,
+    pub height: f32,
+}
+
+impl VirtualResolution {
+    // The virtual resolution for a window of the given logical size
+    pub fn for_window(width: f32, height: f32) -> Self {
+        Self {
+            width: VIRTUAL_HEIGHT * width / height,
+            height: VIRTUAL_HEIGHT,
+        }
+    }
+
+    // The cursor's position in world units, with the origin in the middle of the window and y
+    // pointing up, if it is over the window
+    pub fn cursor_position(&self, window: &Window) -> Option<Vec2> {
+        let cursor = window.cursor_position()?;
+        Some(Vec2::new(
+            (cursor.x / window.width() - 0.5) * self.width,
+            (0.5 - cursor.y / window.height()) * self.height,
+        ))
+    }
+}
+
+impl Default for VirtualResolution {
+    fn default() -> Self {
+        Self::for_window(1280.0, 720.0)
+    }
+}
 
+// Setup camera (runs once at startup)
+pub fn setup_camera(
+    mut commands: Commands,
+    window_query: Query<&Window>,
+    mut resolution: ResMut<VirtualResolution>,
+) {
+    if let Some(window) = window_query.iter().next() {
+        *resolution = VirtualResolution::for_window(window.width(), window.height());
+    }
 
     commands.spawn((Camera2d, Projection::from(OrthographicProjection {
         scaling_mode: bevy::camera::ScalingMode::FixedVertical {
-                viewport_height: WINDOW_HEIGHT,
+                viewport_height: VIRTUAL_HEIGHT,
             },
             ..OrthographicProjection::default_2d()
     })));
@@ -0,0 +1,252 @@
+use bevy::prelude::*;
+use cardigan_core::{CardId, TargetChoice};
+use crate::{GameState, InMatch};
+use crate::abilities::activate_ability_system;
+use crate::combat::{zone_slot, LifeLabel};
+use crate::gameplay::{card_drag_system, sync_cards_system, Card, CardZone, GameplayState};
+use crate::pause::handle_pause_input;
+use crate::picking::topmost_at;
+use crate::startup::VirtualResolution;
+use crate::turn::TurnPhase;
+
+// How much wider than a card the glow behind a legal target is
+const TARGET_HIGHLIGHT_MARGIN: f32 = 16.0;
+
+// Plugin initializer for choosing targets for spells, creatures and abilities
+pub fn init_targeting_systems(app: &mut App) {
+    app.insert_gizmo_config(
+        TargetingGizmos,
+        GizmoConfig {
+            line: GizmoLineConfig {
+                width: 6.0,
+                ..default()
+            },
+            ..default()
+        },
+    )
+    .add_systems(OnExit(TurnPhase::Main), cancel_targeting)
+    .add_systems(OnExit(InMatch), cancel_targeting)
+    .add_systems(
+        Update,
+        (
+            // Runs before the systems that start targeting, so the click that starts it
+            // isn't also taken as the choice; after the pause key, so Escape only cancels
+            target_pick_system
+                .before(card_drag_system)
+                .before(activate_ability_system)
+                .before(sync_cards_system)
+                .after(handle_pause_input)
+                .run_if(resource_exists::<Targeting>),
+            (target_highlight_system, targeting_arrow_system).run_if(resource_exists::<Targeting>),
+            clear_target_highlights_system.run_if(resource_removed::<Targeting>),
+            life_target_highlight_system,
+        )
+            .run_if(in_state(GameState::Playing)),
+    );
+}
+
+// Gizmo group for the targeting arrow, drawn thicker than the default lines
+#[derive(Default, Reflect, GizmoConfigGroup)]
+pub struct TargetingGizmos;
+
+// What happens once a target is chosen
+#[derive(Debug, Clone, Copy)]
+pub enum PendingAction {
+    // Play the hand card `card` into the player's slot `slot`
+    Play { card: CardId, slot: usize },
+    // Use ability `ability` of the player's creature in slot `slot`
+    Activate { slot: usize, ability: usize },
+}
+
+// The player is choosing a target for `action`. Only `legal` targets can be picked; cards
+// can't be played or used until the choice is made or cancelled.
+#[derive(Resource)]
+pub struct Targeting {
+    pub action: PendingAction,
+    pub source: Entity,
+    pub legal: Vec<TargetChoice>,
+}
+
+// Marker for the card a target is being chosen for; the hand layout leaves it where it is
+#[derive(Component)]
+pub struct TargetingSource;
+
+// Marker for the glow behind a creature that can be targeted
+#[derive(Component)]
+pub struct TargetHighlight;
+
+// Enter targeting mode for `action`, with the arrow starting at `source`
+pub fn start_targeting(
+    commands: &mut Commands,
+    action: PendingAction,
+    source: Entity,
+    legal: Vec<TargetChoice>,
+) {
+    commands.entity(source).insert(TargetingSource);
+    commands.insert_resource(Targeting { action, source, legal });
+}
+
+// Leave targeting mode without doing anything; a card being played returns to the hand
+pub fn cancel_targeting(mut commands: Commands, targeting: Option<Res<Targeting>>) {
+    if let Some(targeting) = targeting {
+        end_targeting(&mut commands, &targeting);
+    }
+}
+
+fn end_targeting(commands: &mut Commands, targeting: &Targeting) {
+    if let Ok(mut source) = commands.get_entity(targeting.source) {
+        source.remove::<TargetingSource>();
+    }
+    commands.remove_resource::<Targeting>();
+}
+
+// Pick a target with a left click on a highlighted creature or life total; a right click or
+// Escape cancels
+pub fn target_pick_system(
+    mut commands: Commands,
+    targeting: Res<Targeting>,
+    card_query: Query<(&Card, &CardZone, &Transform, &Sprite)>,
+    label_query: Query<(&LifeLabel, &Interaction)>,
+    mut gameplay_state: ResMut<GameplayState>,
+    mut mouse_button: ResMut<ButtonInput<MouseButton>>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    window_query: Query<&Window>,
+    resolution: Res<VirtualResolution>,
+) {
+    if mouse_button.just_pressed(MouseButton::Right) || keyboard.just_pressed(KeyCode::Escape) {
+        mouse_button.clear_just_pressed(MouseButton::Right);
+        end_targeting(&mut commands, &targeting);
+        return;
+    }
+    if !mouse_button.just_pressed(MouseButton::Left) {
+        return;
+    }
+
+    let Some(window) = window_query.iter().next() else {
+        return;
+    };
+
+    let cursor_world_pos = resolution.cursor_position(window);
+
+    // A pressed life label, or else the topmost legal creature under the cursor
+    let picked_player = label_query.iter().find_map(|(label, interaction)| {
+        (*interaction == Interaction::Pressed).then_some(TargetChoice::Player(label.side))
+    });
+    let picked_creature = cursor_world_pos.and_then(|cursor_pos| {
+        let board_cards = card_query.iter().filter_map(|(_, zone, transform, sprite)| {
+            let (side, slot) = zone_slot(zone)?;
+            Some((TargetChoice::Creature { side, slot }, transform, sprite))
+        });
+        topmost_at(cursor_pos, board_cards)
+    });
+    let Some(target) = picked_player.or(picked_creature).filter(|target| targeting.legal.contains(target)) else {
+        return;
+    };
+
+    // The click is used up here, so it doesn't also pick up or use a card
+    mouse_button.clear_just_pressed(MouseButton::Left);
+    let result = match targeting.action {
+        PendingAction::Play { card, slot } => {
+            match gameplay_state.player.hand.iter().position(|held| held.id == card) {
+                Some(hand_index) => gameplay_state.play(hand_index, slot, Some(target)),
+                None => Ok(()),
+            }
+        }
+        PendingAction::Activate { slot, ability } => gameplay_state.activate(slot, ability, Some(target)),
+    };
+    if let Err(error) = result {
+        let name = card_query.get(targeting.source).map_or("card", |(card, ..)| card.data.name.as_str());
+        warn!("Couldn't use {} on {:?}: {}", name, target, error);
+    }
+    end_targeting(&mut commands, &targeting);
+}
+
+// Put a glow behind every creature that can be targeted
+pub fn target_highlight_system(
+    mut commands: Commands,
+    targeting: Res<Targeting>,
+    card_query: Query<(Entity, &Card, &CardZone, Option<&Children>)>,
+    highlight_query: Query<Entity, With<TargetHighlight>>,
+) {
+    for (entity, card, zone, children) in card_query.iter() {
+        let legal = zone_slot(zone)
+            .is_some_and(|(side, slot)| targeting.legal.contains(&TargetChoice::Creature { side, slot }));
+        let highlight = children
+            .into_iter()
+            .flat_map(|children| children.iter())
+            .find(|child| highlight_query.contains(*child));
+
+        match (legal, highlight) {
+            (true, None) => {
+                commands.entity(entity).with_children(|parent| {
+                    parent.spawn((
+                        Sprite {
+                            color: Color::srgba(1.0, 0.85, 0.3, 0.8),
+                            custom_size: Some(card.base_size + Vec2::splat(TARGET_HIGHLIGHT_MARGIN)),
+                            ..default()
+                        },
+                        Transform::from_xyz(0.0, 0.0, -1.5),
+                        TargetHighlight,
+                    ));
+                });
+            }
+            (false, Some(highlight)) => commands.entity(highlight).despawn(),
+            _ => {}
+        }
+    }
+}
+
+// Remove the glows once targeting is over
+pub fn clear_target_highlights_system(
+    mut commands: Commands,
+    highlight_query: Query<Entity, With<TargetHighlight>>,
+) {
+    for entity in highlight_query.iter() {
+        commands.entity(entity).despawn();
+    }
+}
+
+// Light up the life totals of players that can be targeted, brighter under the cursor
+pub fn life_target_highlight_system(
+    mut label_query: Query<(&LifeLabel, &Interaction, &mut BackgroundColor)>,
+    targeting: Option<Res<Targeting>>,
+) {
+    for (label, interaction, mut background) in label_query.iter_mut() {
+        let legal = targeting
+            .as_ref()
+            .is_some_and(|targeting| targeting.legal.contains(&TargetChoice::Player(label.side)));
+        let color = match interaction {
+            _ if !legal => Color::NONE,
+            Interaction::Hovered | Interaction::Pressed => Color::srgba(1.0, 0.85, 0.3, 0.6),
+            Interaction::None => Color::srgba(1.0, 0.85, 0.3, 0.3),
+        };
+        if background.0 != color {
+            background.0 = color;
+        }
+    }
+}
+
+// Draw an arrow from the card being used to the cursor
+pub fn targeting_arrow_system(
+    mut gizmos: Gizmos<TargetingGizmos>,
+    targeting: Res<Targeting>,
+    source_query: Query<&GlobalTransform>,
+    window_query: Query<&Window>,
+    resolution: Res<VirtualResolution>,
+) {
+    let Ok(source) = source_query.get(targeting.source) else {
+        return;
+    };
+
+    let Some(window) = window_query.iter().next() else {
+        return;
+    };
+
+    let Some(cursor_world_pos) = resolution.cursor_position(window) else {
+        return;
+    };
+
+    gizmos
+        .arrow_2d(source.translation().truncate(), cursor_world_pos, Color::srgb(1.0, 0.85, 0.3))
+        .with_tip_length(24.0);
+}
@@ -0,0 +1,314 @@
+use bevy::prelude::*;
+use cardigan_core::PlayerSide;
+use crate::{GameState, InMatch};
+use crate::combat::{AttackerSelection, DeclaredAttackers};
+use crate::gameplay::{GameEntity, GameplayState};
+
+// Plugin initializer for turn structure systems
+pub fn init_turn_systems(app: &mut App) {
+    app.add_sub_state::<TurnPhase>()
+        .add_systems(OnEnter(InMatch), setup_turn_ui)
+        .add_systems(OnEnter(TurnPhase::Start), start_turn)
+        .add_systems(OnEnter(TurnPhase::End), end_turn)
+        .add_systems(
+            Update,
+            (
+                advance_phase_system,
+                end_turn_button_system,
+                end_turn_button_interaction,
+                end_turn_label_system,
+                turn_label_system,
+            )
+            .run_if(in_state(GameState::Playing)),
+        );
+}
+
+// Phases of a single turn. Exists for as long as a match does, so pausing keeps the phase.
+#[derive(SubStates, Debug, Clone, Copy, Default, Eq, PartialEq, Hash)]
+#[source(InMatch = InMatch)]
+pub enum TurnPhase {
+    // Before the first turn, while opening hands are kept or redrawn
+    #[default]
+    Mulligan,
+    Start,
+    Draw,
+    Main,
+    Combat,
+    End,
+}
+
+impl TurnPhase {
+    pub fn label(&self) -> &'static str {
+        match self {
+            TurnPhase::Mulligan => "Mulligan",
+            TurnPhase::Start => "Start",
+            TurnPhase::Draw => "Draw",
+            TurnPhase::Main => "Main",
+            TurnPhase::Combat => "Combat",
+            TurnPhase::End => "End",
+        }
+    }
+}
+
+// Run condition: the local player has priority. While the stack is empty that is the active
+// player; otherwise it is whoever may respond to the top of the stack.
+pub fn player_has_priority(gameplay_state: Option<Res<GameplayState>>) -> bool {
+    gameplay_state.is_some_and(|gameplay_state| gameplay_state.priority == PlayerSide::Player)
+}
+
+// Run condition: the local player can play cards and use abilities, either in their own main
+// phase or in response to something on the stack
+pub fn player_can_act(
+    gameplay_state: Option<Res<GameplayState>>,
+    phase: Option<Res<State<TurnPhase>>>,
+) -> bool {
+    let (Some(gameplay_state), Some(phase)) = (gameplay_state, phase) else {
+        return false;
+    };
+    gameplay_state.priority == PlayerSide::Player
+        && (!gameplay_state.stack.is_empty() || *phase.get() == TurnPhase::Main)
+}
+
+// Component for the "End Turn" button
+#[derive(Component)]
+pub struct EndTurnButton;
+
+// Component to mark the End Turn button's text, which says what the button does next
+#[derive(Component)]
+pub struct EndTurnLabel;
+
+// Component to mark the turn/phase label
+#[derive(Component)]
+pub struct TurnLabel;
+
+// Setup the turn UI (runs when a match starts)
+pub fn setup_turn_ui(mut commands: Commands) {
+    // Right-hand column, vertically centered between the two play areas
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                right: Val::Px(20.0),
+                top: Val::Percent(50.0),
+                margin: UiRect::top(Val::Px(-60.0)),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            GameEntity,
+        ))
+        .with_children(|parent| {
+            // Turn and phase label
+            parent.spawn((
+                Text::new(""),
+                TextFont {
+                    font_size: 22.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.9, 0.9, 0.95)),
+                Node {
+                    margin: UiRect::bottom(Val::Px(10.0)),
+                    ..default()
+                },
+                TurnLabel,
+            ));
+
+            // End Turn button
+            parent
+                .spawn((
+                    Button,
+                    Node {
+                        width: Val::Px(180.0),
+                        height: Val::Px(50.0),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        border: UiRect::all(Val::Px(2.0)),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgb(0.15, 0.15, 0.2)),
+                    BorderColor::from(Color::srgb(0.4, 0.4, 0.5)),
+                    EndTurnButton,
+                ))
+                .with_children(|parent| {
+                    parent.spawn((
+                        Text::new("END TURN"),
+                        TextFont {
+                            font_size: 28.0,
+                            ..default()
+                        },
+                        TextColor(Color::srgb(0.9, 0.9, 0.95)),
+                        EndTurnLabel,
+                    ));
+                });
+        });
+}
+
+// Begin a new turn: creatures get ready, mana refills and start-of-turn triggers go on the stack
+pub fn start_turn(mut gameplay_state: ResMut<GameplayState>) {
+    gameplay_state.start_turn();
+}
+
+// Put end-of-turn triggers on the stack
+pub fn end_turn(mut gameplay_state: ResMut<GameplayState>) {
+    gameplay_state.end_turn();
+}
+
+// The start, draw, combat and end phases run by themselves: each moves on once everything it
+// put on the stack has resolved. Combat also waits for its attackers to be declared and to
+// attack; the end phase waits for the active player to discard down to the maximum hand size;
+// leaving it hands the turn to the other player. The first turn starts once both players have
+// kept or redrawn their opening hand.
+pub fn advance_phase_system(
+    mut gameplay_state: ResMut<GameplayState>,
+    attacker_selection: Option<Res<AttackerSelection>>,
+    declared_attackers: Option<Res<DeclaredAttackers>>,
+    phase: Res<State<TurnPhase>>,
+    mut next_phase: ResMut<NextState<TurnPhase>>,
+) {
+    if !gameplay_state.stack.is_empty() {
+        return;
+    }
+
+    match phase.get() {
+        TurnPhase::Mulligan => {
+            if gameplay_state.player.mulligan_done && gameplay_state.opponent.mulligan_done {
+                next_phase.set(TurnPhase::Start);
+            }
+        }
+        TurnPhase::Start => next_phase.set(TurnPhase::Draw),
+        TurnPhase::Draw => next_phase.set(TurnPhase::Main),
+        TurnPhase::Combat => {
+            if attacker_selection.is_none() && declared_attackers.is_none() {
+                next_phase.set(TurnPhase::End);
+            }
+        }
+        TurnPhase::End => {
+            if gameplay_state.hand_overflow(gameplay_state.active) > 0 {
+                return;
+            }
+            gameplay_state.pass_turn();
+            next_phase.set(TurnPhase::Start);
+        }
+        TurnPhase::Main => {}
+    }
+}
+
+// Whether the player can use the End Turn button: to end their main phase once the stack is
+// empty, or to declare the attackers they picked
+fn end_turn_button_enabled(
+    phase: &TurnPhase,
+    gameplay_state: &GameplayState,
+    attacker_selection: Option<&AttackerSelection>,
+) -> bool {
+    gameplay_state.active == PlayerSide::Player
+        && gameplay_state.stack.is_empty()
+        && (*phase == TurnPhase::Main || attacker_selection.is_some())
+}
+
+// Handle End Turn clicks: in the main phase they move on to combat, and while attackers are
+// being picked they declare them
+pub fn end_turn_button_system(
+    mut commands: Commands,
+    interaction_query: Query<&Interaction, (Changed<Interaction>, With<EndTurnButton>)>,
+    phase: Res<State<TurnPhase>>,
+    gameplay_state: Res<GameplayState>,
+    attacker_selection: Option<Res<AttackerSelection>>,
+    mut next_phase: ResMut<NextState<TurnPhase>>,
+) {
+    for interaction in interaction_query.iter() {
+        if *interaction != Interaction::Pressed
+            || !end_turn_button_enabled(phase.get(), &gameplay_state, attacker_selection.as_deref())
+        {
+            continue;
+        }
+
+        match &attacker_selection {
+            Some(selection) => {
+                commands.insert_resource(DeclaredAttackers {
+                    slots: selection.slots.clone(),
+                });
+                commands.remove_resource::<AttackerSelection>();
+            }
+            None => next_phase.set(TurnPhase::Combat),
+        }
+    }
+}
+
+// End Turn button colors; the button is dimmed while it can't be used
+pub fn end_turn_button_interaction(
+    mut button_query: Query<
+        (&Interaction, &mut BackgroundColor, &mut BorderColor),
+        With<EndTurnButton>,
+    >,
+    phase: Res<State<TurnPhase>>,
+    gameplay_state: Res<GameplayState>,
+    attacker_selection: Option<Res<AttackerSelection>>,
+) {
+    let enabled = end_turn_button_enabled(phase.get(), &gameplay_state, attacker_selection.as_deref());
+
+    for (interaction, mut bg_color, mut border_color) in button_query.iter_mut() {
+        if !enabled {
+            *bg_color = BackgroundColor(Color::srgb(0.1, 0.1, 0.12));
+            *border_color = BorderColor::from(Color::srgb(0.25, 0.25, 0.3));
+            continue;
+        }
+
+        match *interaction {
+            Interaction::Pressed => {
+                *bg_color = BackgroundColor(Color::srgb(0.25, 0.25, 0.3));
+                *border_color = BorderColor::from(Color::srgb(0.6, 0.6, 0.7));
+            }
+            Interaction::Hovered => {
+                *bg_color = BackgroundColor(Color::srgb(0.2, 0.2, 0.25));
+                *border_color = BorderColor::from(Color::srgb(0.7, 0.7, 0.8));
+            }
+            Interaction::None => {
+                *bg_color = BackgroundColor(Color::srgb(0.15, 0.15, 0.2));
+                *border_color = BorderColor::from(Color::srgb(0.4, 0.4, 0.5));
+            }
+        }
+    }
+}
+
+// While attackers are being picked the End Turn button declares them
+pub fn end_turn_label_system(
+    mut label_query: Query<&mut Text, With<EndTurnLabel>>,
+    attacker_selection: Option<Res<AttackerSelection>>,
+) {
+    let value = match attacker_selection.as_deref() {
+        Some(selection) if selection.slots.is_empty() => "NO ATTACK".to_string(),
+        Some(selection) => format!("ATTACK ({})", selection.slots.len()),
+        None => "END TURN".to_string(),
+    };
+
+    for mut text in label_query.iter_mut() {
+        if text.0 != value {
+            text.0 = value.clone();
+        }
+    }
+}
+
+// Keep the turn label in sync with the match state
+pub fn turn_label_system(
+    mut label_query: Query<&mut Text, With<TurnLabel>>,
+    phase: Res<State<TurnPhase>>,
+    gameplay_state: Res<GameplayState>,
+) {
+    if !phase.is_changed() && !gameplay_state.is_changed() {
+        return;
+    }
+
+    let whose = match gameplay_state.active {
+        PlayerSide::Player => "Your",
+        PlayerSide::Opponent => "Opponent's",
+    };
+
+    for mut text in label_query.iter_mut() {
+        text.0 = format!(
+            "Turn {}\n{} {} phase",
+            gameplay_state.turn_number,
+            whose,
+            phase.get().label()
+        );
+    }
+}